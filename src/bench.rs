@@ -0,0 +1,231 @@
+use crate::cli::{BenchOptions, Selection};
+use crate::input::InputSource;
+use crate::parsing::{parse_value, split_at_delimiter, ParseError};
+use crate::solution::{DynSolution, Registry};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct Statistics {
+    pub(crate) min: f64,
+    pub(crate) median: f64,
+    pub(crate) mean: f64,
+    pub(crate) p95: f64,
+    pub(crate) stddev: f64,
+}
+
+fn milliseconds(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000_f64
+}
+
+pub(crate) fn compute_statistics(samples: &[Duration]) -> Statistics {
+    let mut samples: Vec<f64> = samples.iter().map(|&sample| milliseconds(sample)).collect();
+    samples.sort_unstable_by(f64::total_cmp);
+
+    let count: usize = samples.len();
+    let mean: f64 = samples.iter().sum::<f64>() / count as f64;
+    let variance: f64 = samples
+        .iter()
+        .map(|sample| (sample - mean).powi(2))
+        .sum::<f64>()
+        / count as f64;
+
+    let median: f64 = match count % 2 {
+        0 => (samples[count / 2 - 1] + samples[count / 2]) / 2.0,
+        _ => samples[count / 2],
+    };
+
+    let p95_rank: usize = (count * 95).div_ceil(100);
+
+    Statistics {
+        min: samples[0],
+        median,
+        mean,
+        p95: samples[p95_rank.max(1) - 1],
+        stddev: variance.sqrt(),
+    }
+}
+
+fn measure(
+    solution: &dyn DynSolution,
+    file_content: &str,
+    warmup: usize,
+    runs: usize,
+) -> Result<Vec<Duration>, Box<dyn std::error::Error>> {
+    let mut samples: Vec<Duration> = Vec::with_capacity(runs);
+
+    for run in 0..warmup + runs {
+        let start = Instant::now();
+        let parsed = solution.parse_boxed(file_content)?;
+        solution.solve_boxed(parsed.as_ref())?;
+
+        if run >= warmup {
+            samples.push(start.elapsed());
+        }
+    }
+
+    Ok(samples)
+}
+
+pub(crate) fn parse_baseline(file_content: &str) -> Result<HashMap<(u8, u8), f64>, ParseError> {
+    let mut baseline: HashMap<(u8, u8), f64> = HashMap::new();
+
+    for (line_index, line) in file_content.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (day, rest) = split_at_delimiter(line_index, line, line, " ", "day part median_ms")?;
+        let (part, median) = split_at_delimiter(line_index, line, rest, " ", "day part median_ms")?;
+
+        baseline.insert(
+            (
+                parse_value(line_index, line, day, "a day number")?,
+                parse_value(line_index, line, part, "a part number")?,
+            ),
+            parse_value(line_index, line, median, "a median time in milliseconds")?,
+        );
+    }
+
+    Ok(baseline)
+}
+
+pub(crate) fn format_baseline(results: &[(u8, u8, Statistics)]) -> String {
+    let mut lines: Vec<String> = vec![String::from("# day part median_ms")];
+
+    for (day, part, statistics) in results {
+        lines.push(format!("{} {} {:.6}", day, part, statistics.median));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+pub(crate) fn change_in_percent(baseline: f64, current: f64) -> f64 {
+    (current - baseline) / baseline * 100.0
+}
+
+pub(crate) fn run(
+    registry: &Registry,
+    selection: &Selection,
+    input_source: &InputSource,
+    options: &BenchOptions,
+) -> bool {
+    let baseline: HashMap<(u8, u8), f64> = match options.baseline.as_deref().map(|path| {
+        let file_content: String = std::fs::read_to_string(path)
+            .map_err(|error| format!("Failed to read '{}': {}", path, error))?;
+        parse_baseline(&file_content).map_err(|error| format!("'{}': {}", path, error))
+    }) {
+        Some(Ok(baseline)) => baseline,
+        Some(Err(error)) => {
+            eprintln!("{}", error);
+            return false;
+        }
+        None => HashMap::new(),
+    };
+
+    let mut results: Vec<(u8, u8, Statistics)> = Vec::new();
+    let mut succeeded: bool = true;
+
+    for solution in registry
+        .iter()
+        .filter(|solution| selection.includes(solution.day(), solution.part()))
+    {
+        let samples = input_source.read(solution.day()).and_then(|file_content| {
+            measure(solution, &file_content, options.warmup, options.runs)
+        });
+
+        let statistics: Statistics = match samples {
+            Ok(samples) => compute_statistics(&samples),
+            Err(error) => {
+                eprintln!(
+                    "Day {} {} (Part {}): {}",
+                    solution.day(),
+                    solution.title(),
+                    solution.part(),
+                    error
+                );
+                succeeded = false;
+                continue;
+            }
+        };
+
+        println!(
+            "Day {} {} (Part {}): min {:.3}ms, median {:.3}ms, mean {:.3}ms, p95 {:.3}ms, stddev {:.3}ms ({} runs).",
+            solution.day(),
+            solution.title(),
+            solution.part(),
+            statistics.min,
+            statistics.median,
+            statistics.mean,
+            statistics.p95,
+            statistics.stddev,
+            options.runs
+        );
+
+        if let Some(&baseline_median) = baseline.get(&(solution.day(), solution.part())) {
+            let change: f64 = change_in_percent(baseline_median, statistics.median);
+            let regression: bool = change > options.threshold;
+
+            println!(
+                "  Baseline median {:.3}ms, change {:+.1}%{}",
+                baseline_median,
+                change,
+                match regression {
+                    true => format!(" (regression above {}%)", options.threshold),
+                    false => String::new(),
+                }
+            );
+
+            succeeded &= !regression;
+        }
+
+        results.push((solution.day(), solution.part(), statistics));
+    }
+
+    if let Some(path) = options.save_baseline.as_deref() {
+        match std::fs::write(path, format_baseline(&results)) {
+            Ok(()) => println!("Baseline saved to '{}'.", path),
+            Err(error) => {
+                eprintln!("Failed to write '{}': {}", path, error);
+                succeeded = false;
+            }
+        }
+    }
+
+    succeeded
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::*;
+
+    #[test]
+    fn compute_statistics_of_samples() {
+        let samples: Vec<Duration> = [4, 1, 3, 2, 10]
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect();
+        let statistics = compute_statistics(&samples);
+        assert_eq!(statistics.min, 1.0);
+        assert_eq!(statistics.median, 3.0);
+        assert_eq!(statistics.mean, 4.0);
+        assert_eq!(statistics.p95, 10.0);
+        assert!((statistics.stddev - 10_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn save_and_compare_baseline() {
+        let statistics = compute_statistics(&[Duration::from_millis(2)]);
+        let file_content = format_baseline(&[(9, 2, statistics)]);
+        assert_eq!(file_content, "# day part median_ms\n9 2 2.000000\n");
+
+        let baseline = parse_baseline(&file_content).unwrap();
+        assert_eq!(baseline.get(&(9, 2)), Some(&2.0));
+        assert_eq!(change_in_percent(2.0, 2.5), 25.0);
+
+        assert_eq!(
+            parse_baseline("9 2 fast").unwrap_err().to_string(),
+            "Line 1, column 5: invalid value 'fast', expected a median time in milliseconds"
+        );
+    }
+}
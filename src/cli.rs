@@ -0,0 +1,904 @@
+use crate::day_14_restroom_redoubt::room::{parse_room_size, RoomSize};
+use crate::day_17_chronospatial_computer::vm::DEFAULT_STEP_LIMIT;
+use crate::day_18_ram_run::solver::{parse_solver, BlockingByteSolver};
+use crate::render::RenderFormat;
+use std::fmt;
+
+pub(crate) const USAGE: &str = "\
+Usage: advent_of_code_2024 [run] [OPTIONS]
+       advent_of_code_2024 bench [OPTIONS] [BENCH OPTIONS]
+       advent_of_code_2024 vm <disassemble|assemble|debug|run> [--input <FILE>] [VM OPTIONS]
+       advent_of_code_2024 circuit <repair|dot|verilog> [--input <FILE>]
+       advent_of_code_2024 tree [--input <FILE>] [TREE OPTIONS]
+       advent_of_code_2024 warehouse [--input <FILE>] [WAREHOUSE OPTIONS]
+       advent_of_code_2024 routes [--input <FILE>] [ROUTES OPTIONS]
+       advent_of_code_2024 list
+
+Options:
+  --day <N>        Run a single day (can be repeated)
+  --days <LIST>    Run a list of days, e.g. 10-15 or 1,3,5-7
+  --part <P>       Run only part 1 or part 2
+  --all            Run every day (default)
+  --input <FILE>   Read puzzle input from a file, or from stdin when FILE is '-'
+  --input-dir <DIR>
+                   Read puzzle input from DIR/XX.txt (default: $AOC_INPUT_DIR or ./input)
+  --format <FORMAT>
+                   Output format: text (default), json or csv
+  --visualize <FORMAT>
+                   Render grid states of days 6, 14, 15, 16, 18 and 20 as ansi or text
+                   frames on stdout, or as ppm or png image sequences
+  --frames-dir <DIR>
+                   Write image frames to DIR (default: ./frames)
+  -h, --help       Print this message
+
+Puzzle options (run, bench and tree):
+  --room-size <WxH>
+                   Day 14 room, e.g. 11x7 (default: inferred from the guard positions)
+  --byte-solver <NAME>
+                   Day 18 Part 2 search: union-find or binary-search (default: union-find)
+  --memory-size <N>
+                   Day 18 memory space width and height (default: inferred from the bytes)
+  --fallen-bytes <N>
+                   Day 18 Part 1 number of fallen bytes (default: 12 for 7x7, 1024 for 71x71)
+
+Bench options:
+  --runs <N>       Number of measured runs (default: 20)
+  --warmup <N>     Number of runs before measuring (default: 3)
+  --save-baseline <FILE>
+                   Save median times to FILE
+  --baseline <FILE>
+                   Compare median times against FILE
+  --threshold <PCT>
+                   Slowdown in percent reported as a regression (default: 10)
+
+Vm commands (Day 17 computer, reading the Day 17 input unless --input is given):
+  disassemble      Print the program as mnemonics
+  assemble         Turn mnemonics back into the puzzle input format
+  debug            Step through the program interactively (type 'help' for commands)
+  run              Run the program and print its output
+
+Vm options:
+  --break <ADDR>   Start the debugger with a breakpoint at ADDR (can be repeated)
+  --width <BITS>   Register width for run: 32, 64 (default) or 128
+  --max-steps <N>  Stop run with an error after N instructions (default: 1000000)
+
+Circuit commands (Day 24 gates, reading the Day 24 input unless --input is given):
+  repair           Check the circuit is a ripple-carry adder and find the swapped outputs
+  dot              Print the circuit as a Graphviz graph with suspected swapped wires in red
+  verilog          Print the circuit as a structural Verilog module
+
+Tree options (Day 14 guards, reading the Day 14 input unless --input is given):
+  --detector <NAME>
+                   Score seconds with closeness, entropy, run or density (can be repeated,
+                   default: all)
+  --top <K>        Number of best scoring seconds reported per detector (default: 5)
+
+Routes options (Day 16 maze, printing the lowest scoring routes with their forward steps and
+turns, reading the Day 16 input unless --input is given):
+  --max-routes <N> Number of routes printed at most (default: 10)
+
+Warehouse options (Day 15 map, moved with the arrow keys, reading the Day 15 input unless
+--input is given):
+  --scale <N>      Widen the warehouse N times, e.g. 2 for part 2 (default: 1)
+  --record <FILE>  Save the map and the session's moves to FILE in the puzzle format
+
+Environment:
+  AOC_INPUT_DIR    Default input directory";
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum CliError {
+    UnknownArgument(String),
+    MissingValue(String),
+    InvalidValue(String, String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::UnknownArgument(argument) => write!(f, "Unknown argument: '{}'", argument),
+            CliError::MissingValue(flag) => write!(f, "Missing value for '{}'", flag),
+            CliError::InvalidValue(flag, value) => {
+                write!(f, "Invalid value for '{}': '{}'", flag, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Command {
+    Run(RunOptions),
+    Bench(RunOptions, BenchOptions),
+    Vm(RunOptions, VmOptions),
+    Circuit(RunOptions, CircuitAction),
+    Tree(RunOptions, TreeOptions),
+    Warehouse(RunOptions, WarehouseOptions),
+    Routes(RunOptions, RoutesOptions),
+    List,
+    Help,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub(crate) enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub(crate) struct Selection {
+    pub(crate) days: Vec<u8>,
+    pub(crate) part: Option<u8>,
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub(crate) struct RunOptions {
+    pub(crate) selection: Selection,
+    pub(crate) input: Option<String>,
+    pub(crate) input_dir: Option<String>,
+    pub(crate) format: OutputFormat,
+    pub(crate) visualize: Option<RenderFormat>,
+    pub(crate) frames_dir: Option<String>,
+    pub(crate) puzzle: PuzzleOptions,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub(crate) struct PuzzleOptions {
+    pub(crate) room_size: Option<RoomSize>,
+    pub(crate) byte_solver: BlockingByteSolver,
+    pub(crate) memory_size: Option<usize>,
+    pub(crate) fallen_bytes: Option<usize>,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct BenchOptions {
+    pub(crate) runs: usize,
+    pub(crate) warmup: usize,
+    pub(crate) save_baseline: Option<String>,
+    pub(crate) baseline: Option<String>,
+    pub(crate) threshold: f64,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum VmAction {
+    Disassemble,
+    Assemble,
+    Debug,
+    Run,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct VmOptions {
+    pub(crate) action: VmAction,
+    pub(crate) breakpoints: Vec<usize>,
+    pub(crate) width: u32,
+    pub(crate) max_steps: usize,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum CircuitAction {
+    Repair,
+    Dot,
+    Verilog,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum DetectorKind {
+    Closeness,
+    Entropy,
+    Run,
+    Density,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct TreeOptions {
+    pub(crate) detectors: Vec<DetectorKind>,
+    pub(crate) top: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct RoutesOptions {
+    pub(crate) max_routes: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct WarehouseOptions {
+    pub(crate) scale: usize,
+    pub(crate) record: Option<String>,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            runs: 20,
+            warmup: 3,
+            save_baseline: None,
+            baseline: None,
+            threshold: 10.0,
+        }
+    }
+}
+
+impl Selection {
+    pub(crate) fn includes(&self, day: u8, part: u8) -> bool {
+        (self.days.is_empty() || self.days.contains(&day))
+            && self.part.is_none_or(|selected_part| selected_part == part)
+    }
+}
+
+fn parse_day(flag: &str, value: &str) -> Result<u8, CliError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| CliError::InvalidValue(flag.to_owned(), value.to_owned()))
+}
+
+fn parse_format(flag: &str, value: &str) -> Result<OutputFormat, CliError> {
+    match value {
+        "text" => Ok(OutputFormat::Text),
+        "json" => Ok(OutputFormat::Json),
+        "csv" => Ok(OutputFormat::Csv),
+        _ => Err(CliError::InvalidValue(flag.to_owned(), value.to_owned())),
+    }
+}
+
+fn parse_render_format(flag: &str, value: &str) -> Result<RenderFormat, CliError> {
+    match value {
+        "ansi" => Ok(RenderFormat::Ansi),
+        "text" => Ok(RenderFormat::Text),
+        "ppm" => Ok(RenderFormat::Ppm),
+        "png" => Ok(RenderFormat::Png),
+        _ => Err(CliError::InvalidValue(flag.to_owned(), value.to_owned())),
+    }
+}
+
+fn parse_room_size_option(flag: &str, value: &str) -> Result<RoomSize, CliError> {
+    parse_room_size(value).ok_or_else(|| CliError::InvalidValue(flag.to_owned(), value.to_owned()))
+}
+
+fn parse_solver_option(flag: &str, value: &str) -> Result<BlockingByteSolver, CliError> {
+    parse_solver(value).ok_or_else(|| CliError::InvalidValue(flag.to_owned(), value.to_owned()))
+}
+
+fn parse_count(flag: &str, value: &str, minimum: usize) -> Result<usize, CliError> {
+    value
+        .parse()
+        .ok()
+        .filter(|&count| count >= minimum)
+        .ok_or_else(|| CliError::InvalidValue(flag.to_owned(), value.to_owned()))
+}
+
+fn parse_vm_action(flag: &str, value: &str) -> Result<VmAction, CliError> {
+    match value {
+        "disassemble" => Ok(VmAction::Disassemble),
+        "assemble" => Ok(VmAction::Assemble),
+        "debug" => Ok(VmAction::Debug),
+        "run" => Ok(VmAction::Run),
+        _ => Err(CliError::InvalidValue(flag.to_owned(), value.to_owned())),
+    }
+}
+
+fn parse_width(flag: &str, value: &str) -> Result<u32, CliError> {
+    match value {
+        "32" | "64" | "128" => Ok(value.parse().expect("Should be a valid width")),
+        _ => Err(CliError::InvalidValue(flag.to_owned(), value.to_owned())),
+    }
+}
+
+fn parse_circuit_action(flag: &str, value: &str) -> Result<CircuitAction, CliError> {
+    match value {
+        "repair" => Ok(CircuitAction::Repair),
+        "dot" => Ok(CircuitAction::Dot),
+        "verilog" => Ok(CircuitAction::Verilog),
+        _ => Err(CliError::InvalidValue(flag.to_owned(), value.to_owned())),
+    }
+}
+
+fn parse_detector_kind(flag: &str, value: &str) -> Result<DetectorKind, CliError> {
+    match value {
+        "closeness" => Ok(DetectorKind::Closeness),
+        "entropy" => Ok(DetectorKind::Entropy),
+        "run" => Ok(DetectorKind::Run),
+        "density" => Ok(DetectorKind::Density),
+        _ => Err(CliError::InvalidValue(flag.to_owned(), value.to_owned())),
+    }
+}
+
+fn parse_threshold(flag: &str, value: &str) -> Result<f64, CliError> {
+    value
+        .parse()
+        .ok()
+        .filter(|threshold: &f64| threshold.is_finite() && *threshold >= 0.0)
+        .ok_or_else(|| CliError::InvalidValue(flag.to_owned(), value.to_owned()))
+}
+
+fn parse_day_list(flag: &str, value: &str) -> Result<Vec<u8>, CliError> {
+    let mut days: Vec<u8> = Vec::new();
+
+    for item in value.split(',') {
+        match item.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse_day(flag, first)?, parse_day(flag, last)?);
+
+                if first > last {
+                    return Err(CliError::InvalidValue(flag.to_owned(), value.to_owned()));
+                }
+
+                days.extend(first..=last);
+            }
+            None => days.push(parse_day(flag, item)?),
+        }
+    }
+
+    Ok(days)
+}
+
+pub(crate) fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, CliError> {
+    let mut args = args.into_iter().peekable();
+    let mut options = RunOptions::default();
+    let mut bench_options: Option<BenchOptions> = None;
+    let mut vm_options: Option<VmOptions> = None;
+    let mut circuit_action: Option<CircuitAction> = None;
+    let mut tree_options: Option<TreeOptions> = None;
+    let mut warehouse_options: Option<WarehouseOptions> = None;
+    let mut routes_options: Option<RoutesOptions> = None;
+    let mut all = false;
+
+    match args.peek().map(String::as_str) {
+        Some("run") => {
+            args.next();
+        }
+        Some("bench") => {
+            args.next();
+            bench_options = Some(BenchOptions::default());
+        }
+        Some("vm") => {
+            args.next();
+            let action: String = args.next().ok_or(CliError::MissingValue("vm".into()))?;
+            vm_options = Some(VmOptions {
+                action: parse_vm_action("vm", &action)?,
+                breakpoints: Vec::new(),
+                width: 64,
+                max_steps: DEFAULT_STEP_LIMIT,
+            });
+        }
+        Some("circuit") => {
+            args.next();
+            let action: String = args
+                .next()
+                .ok_or(CliError::MissingValue("circuit".into()))?;
+            circuit_action = Some(parse_circuit_action("circuit", &action)?);
+        }
+        Some("tree") => {
+            args.next();
+            tree_options = Some(TreeOptions {
+                detectors: Vec::new(),
+                top: 5,
+            });
+        }
+        Some("warehouse") => {
+            args.next();
+            warehouse_options = Some(WarehouseOptions {
+                scale: 1,
+                record: None,
+            });
+        }
+        Some("routes") => {
+            args.next();
+            routes_options = Some(RoutesOptions { max_routes: 10 });
+        }
+        Some("list") => return Ok(Command::List),
+        _ => {}
+    }
+
+    while let Some(arg) = args.next() {
+        let (flag, inline_value): (String, Option<String>) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => {
+                (flag.to_owned(), Some(value.to_owned()))
+            }
+            _ => (arg, None),
+        };
+
+        let mut value = |flag: &str| -> Result<String, CliError> {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| CliError::MissingValue(flag.to_owned()))
+        };
+
+        if let Some(bench_options) = bench_options.as_mut() {
+            match flag.as_str() {
+                "--runs" => {
+                    bench_options.runs = parse_count(&flag, &value(&flag)?, 1)?;
+                    continue;
+                }
+                "--warmup" => {
+                    bench_options.warmup = parse_count(&flag, &value(&flag)?, 0)?;
+                    continue;
+                }
+                "--save-baseline" => {
+                    bench_options.save_baseline = Some(value(&flag)?);
+                    continue;
+                }
+                "--baseline" => {
+                    bench_options.baseline = Some(value(&flag)?);
+                    continue;
+                }
+                "--threshold" => {
+                    bench_options.threshold = parse_threshold(&flag, &value(&flag)?)?;
+                    continue;
+                }
+                "--format" | "--visualize" | "--frames-dir" => {
+                    return Err(CliError::UnknownArgument(flag))
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(vm_options) = vm_options.as_mut() {
+            match flag.as_str() {
+                "--break" if vm_options.action == VmAction::Debug => {
+                    let address: String = value(&flag)?;
+                    let address: usize = address
+                        .parse()
+                        .map_err(|_| CliError::InvalidValue(flag.clone(), address))?;
+                    vm_options.breakpoints.push(address);
+                    continue;
+                }
+                "--width" if vm_options.action == VmAction::Run => {
+                    vm_options.width = parse_width(&flag, &value(&flag)?)?;
+                    continue;
+                }
+                "--max-steps" if vm_options.action == VmAction::Run => {
+                    vm_options.max_steps = parse_count(&flag, &value(&flag)?, 1)?;
+                    continue;
+                }
+                "--day" | "--days" | "--part" | "--all" | "--format" | "--visualize"
+                | "--frames-dir" => return Err(CliError::UnknownArgument(flag)),
+                _ => {}
+            }
+        }
+
+        if let Some(tree_options) = tree_options.as_mut() {
+            match flag.as_str() {
+                "--detector" => {
+                    let detector: DetectorKind = parse_detector_kind(&flag, &value(&flag)?)?;
+                    tree_options.detectors.push(detector);
+                    continue;
+                }
+                "--top" => {
+                    tree_options.top = parse_count(&flag, &value(&flag)?, 1)?;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(warehouse_options) = warehouse_options.as_mut() {
+            match flag.as_str() {
+                "--scale" => {
+                    warehouse_options.scale = parse_count(&flag, &value(&flag)?, 1)?;
+                    continue;
+                }
+                "--record" => {
+                    warehouse_options.record = Some(value(&flag)?);
+                    continue;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(routes_options) = routes_options.as_mut() {
+            if flag == "--max-routes" {
+                routes_options.max_routes = parse_count(&flag, &value(&flag)?, 1)?;
+                continue;
+            }
+        }
+
+        if (circuit_action.is_some()
+            || tree_options.is_some()
+            || warehouse_options.is_some()
+            || routes_options.is_some())
+            && matches!(
+                flag.as_str(),
+                "--day"
+                    | "--days"
+                    | "--part"
+                    | "--all"
+                    | "--format"
+                    | "--visualize"
+                    | "--frames-dir"
+            )
+        {
+            return Err(CliError::UnknownArgument(flag));
+        }
+
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--all" => all = true,
+            "--day" => options
+                .selection
+                .days
+                .push(parse_day(&flag, &value(&flag)?)?),
+            "--days" => options
+                .selection
+                .days
+                .extend(parse_day_list(&flag, &value(&flag)?)?),
+            "--part" => {
+                let part = value(&flag)?;
+
+                match part.as_str() {
+                    "1" | "2" => options.selection.part = part.parse().ok(),
+                    _ => return Err(CliError::InvalidValue(flag, part)),
+                }
+            }
+            "--input" => options.input = Some(value(&flag)?),
+            "--input-dir" => options.input_dir = Some(value(&flag)?),
+            "--format" => options.format = parse_format(&flag, &value(&flag)?)?,
+            "--visualize" => options.visualize = Some(parse_render_format(&flag, &value(&flag)?)?),
+            "--frames-dir" => options.frames_dir = Some(value(&flag)?),
+            "--room-size" => {
+                options.puzzle.room_size = Some(parse_room_size_option(&flag, &value(&flag)?)?)
+            }
+            "--byte-solver" => {
+                options.puzzle.byte_solver = parse_solver_option(&flag, &value(&flag)?)?
+            }
+            "--memory-size" => {
+                options.puzzle.memory_size = Some(parse_count(&flag, &value(&flag)?, 1)?)
+            }
+            "--fallen-bytes" => {
+                options.puzzle.fallen_bytes = Some(parse_count(&flag, &value(&flag)?, 1)?)
+            }
+            _ => return Err(CliError::UnknownArgument(flag)),
+        }
+    }
+
+    if all {
+        options.selection.days.clear();
+    }
+
+    options.selection.days.sort_unstable();
+    options.selection.days.dedup();
+
+    if let Some(warehouse_options) = warehouse_options {
+        return Ok(Command::Warehouse(options, warehouse_options));
+    }
+
+    if let Some(routes_options) = routes_options {
+        return Ok(Command::Routes(options, routes_options));
+    }
+
+    match (bench_options, vm_options, circuit_action, tree_options) {
+        (Some(bench_options), _, _, _) => Ok(Command::Bench(options, bench_options)),
+        (None, Some(vm_options), _, _) => Ok(Command::Vm(options, vm_options)),
+        (None, None, Some(action), _) => Ok(Command::Circuit(options, action)),
+        (None, None, None, Some(tree_options)) => Ok(Command::Tree(options, tree_options)),
+        (None, None, None, None) => Ok(Command::Run(options)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parse_day_and_part() {
+        let command = parse_args(args("run --day 16 --part 2"));
+        let expected = RunOptions {
+            selection: Selection {
+                days: vec![16],
+                part: Some(2),
+            },
+            ..Default::default()
+        };
+        assert_eq!(command, Ok(Command::Run(expected)));
+    }
+
+    #[test]
+    fn parse_day_ranges() {
+        let command = parse_args(args("--days 10-12,3 --day=1"));
+        let expected = RunOptions {
+            selection: Selection {
+                days: vec![1, 3, 10, 11, 12],
+                part: None,
+            },
+            ..Default::default()
+        };
+        assert_eq!(command, Ok(Command::Run(expected)));
+    }
+
+    #[test]
+    fn parse_list_command() {
+        assert_eq!(parse_args(args("list")), Ok(Command::List));
+    }
+
+    #[test]
+    fn parse_input_options() {
+        let command = parse_args(args(
+            "--day 3 --input - --input-dir=/tmp/aoc --format json --visualize png --frames-dir out",
+        ));
+        let expected = RunOptions {
+            selection: Selection {
+                days: vec![3],
+                part: None,
+            },
+            input: Some(String::from("-")),
+            input_dir: Some(String::from("/tmp/aoc")),
+            format: OutputFormat::Json,
+            visualize: Some(RenderFormat::Png),
+            frames_dir: Some(String::from("out")),
+            puzzle: PuzzleOptions::default(),
+        };
+        assert_eq!(command, Ok(Command::Run(expected)));
+
+        let command = parse_args(args("--day 18 --memory-size 7 --fallen-bytes=12"));
+        let expected = RunOptions {
+            selection: Selection {
+                days: vec![18],
+                part: None,
+            },
+            puzzle: PuzzleOptions {
+                memory_size: Some(7),
+                fallen_bytes: Some(12),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(command, Ok(Command::Run(expected)));
+        assert_eq!(
+            parse_args(args("--day 18 --memory-size 0")),
+            Err(CliError::InvalidValue("--memory-size".into(), "0".into()))
+        );
+    }
+
+    #[test]
+    fn parse_bench_command() {
+        let command = parse_args(args(
+            "bench --day 18 --part 2 --runs 50 --warmup=0 --baseline base.txt --threshold 5 \
+             --byte-solver binary-search",
+        ));
+        let expected_options = RunOptions {
+            selection: Selection {
+                days: vec![18],
+                part: Some(2),
+            },
+            puzzle: PuzzleOptions {
+                byte_solver: BlockingByteSolver::BinarySearch,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let expected_bench_options = BenchOptions {
+            runs: 50,
+            warmup: 0,
+            baseline: Some(String::from("base.txt")),
+            threshold: 5.0,
+            ..Default::default()
+        };
+        assert_eq!(
+            command,
+            Ok(Command::Bench(expected_options, expected_bench_options))
+        );
+
+        assert_eq!(
+            parse_args(args("bench --runs 0")),
+            Err(CliError::InvalidValue("--runs".into(), "0".into()))
+        );
+        assert_eq!(
+            parse_args(args("bench --byte-solver bfs")),
+            Err(CliError::InvalidValue("--byte-solver".into(), "bfs".into()))
+        );
+        assert_eq!(
+            parse_args(args("bench --format json")),
+            Err(CliError::UnknownArgument("--format".into()))
+        );
+        assert_eq!(
+            parse_args(args("--runs 5")),
+            Err(CliError::UnknownArgument("--runs".into()))
+        );
+    }
+
+    #[test]
+    fn parse_vm_command() {
+        let command = parse_args(args("vm debug --break 4 --input 17.txt --break=0"));
+        let expected_options = RunOptions {
+            input: Some(String::from("17.txt")),
+            ..Default::default()
+        };
+        let expected_vm_options = VmOptions {
+            action: VmAction::Debug,
+            breakpoints: vec![4, 0],
+            width: 64,
+            max_steps: DEFAULT_STEP_LIMIT,
+        };
+        assert_eq!(
+            command,
+            Ok(Command::Vm(expected_options, expected_vm_options))
+        );
+
+        let command = parse_args(args("vm run --width 128 --max-steps=500"));
+        let expected_vm_options = VmOptions {
+            action: VmAction::Run,
+            breakpoints: Vec::new(),
+            width: 128,
+            max_steps: 500,
+        };
+        assert_eq!(
+            command,
+            Ok(Command::Vm(RunOptions::default(), expected_vm_options))
+        );
+
+        assert_eq!(
+            parse_args(args("vm execute")),
+            Err(CliError::InvalidValue("vm".into(), "execute".into()))
+        );
+        assert_eq!(
+            parse_args(args("vm run --width 16")),
+            Err(CliError::InvalidValue("--width".into(), "16".into()))
+        );
+        assert_eq!(
+            parse_args(args("vm debug --width 32")),
+            Err(CliError::UnknownArgument("--width".into()))
+        );
+        assert_eq!(
+            parse_args(args("vm disassemble --break 4")),
+            Err(CliError::UnknownArgument("--break".into()))
+        );
+        assert_eq!(
+            parse_args(args("vm assemble --day 17")),
+            Err(CliError::UnknownArgument("--day".into()))
+        );
+    }
+
+    #[test]
+    fn parse_circuit_command() {
+        let command = parse_args(args("circuit repair --input 24.txt"));
+        let expected_options = RunOptions {
+            input: Some(String::from("24.txt")),
+            ..Default::default()
+        };
+        assert_eq!(
+            command,
+            Ok(Command::Circuit(expected_options, CircuitAction::Repair))
+        );
+
+        assert_eq!(
+            parse_args(args("circuit verilog")),
+            Ok(Command::Circuit(
+                RunOptions::default(),
+                CircuitAction::Verilog
+            ))
+        );
+        assert_eq!(
+            parse_args(args("circuit")),
+            Err(CliError::MissingValue("circuit".into()))
+        );
+        assert_eq!(
+            parse_args(args("circuit repair --part 2")),
+            Err(CliError::UnknownArgument("--part".into()))
+        );
+    }
+
+    #[test]
+    fn parse_tree_command() {
+        let command = parse_args(args("tree --detector run --top=3 --detector density"));
+        let expected_tree_options = TreeOptions {
+            detectors: vec![DetectorKind::Run, DetectorKind::Density],
+            top: 3,
+        };
+        assert_eq!(
+            command,
+            Ok(Command::Tree(RunOptions::default(), expected_tree_options))
+        );
+
+        let command = parse_args(args("tree --room-size 11x7"));
+        let expected_options = RunOptions {
+            puzzle: PuzzleOptions {
+                room_size: Some(RoomSize {
+                    width: 11,
+                    height: 7,
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let expected_tree_options = TreeOptions {
+            detectors: Vec::new(),
+            top: 5,
+        };
+        assert_eq!(
+            command,
+            Ok(Command::Tree(expected_options, expected_tree_options))
+        );
+
+        assert_eq!(
+            parse_args(args("tree --room-size 11x0")),
+            Err(CliError::InvalidValue("--room-size".into(), "11x0".into()))
+        );
+        assert_eq!(
+            parse_args(args("tree --detector shape")),
+            Err(CliError::InvalidValue("--detector".into(), "shape".into()))
+        );
+        assert_eq!(
+            parse_args(args("tree --top 0")),
+            Err(CliError::InvalidValue("--top".into(), "0".into()))
+        );
+        assert_eq!(
+            parse_args(args("tree --day 14")),
+            Err(CliError::UnknownArgument("--day".into()))
+        );
+    }
+
+    #[test]
+    fn parse_warehouse_command() {
+        let command = parse_args(args("warehouse --scale 3 --record session.txt"));
+        let expected_warehouse_options = WarehouseOptions {
+            scale: 3,
+            record: Some(String::from("session.txt")),
+        };
+        assert_eq!(
+            command,
+            Ok(Command::Warehouse(
+                RunOptions::default(),
+                expected_warehouse_options
+            ))
+        );
+
+        assert_eq!(
+            parse_args(args("warehouse --scale 0")),
+            Err(CliError::InvalidValue("--scale".into(), "0".into()))
+        );
+        assert_eq!(
+            parse_args(args("warehouse --record")),
+            Err(CliError::MissingValue("--record".into()))
+        );
+        assert_eq!(
+            parse_args(args("warehouse --all")),
+            Err(CliError::UnknownArgument("--all".into()))
+        );
+        assert_eq!(
+            parse_args(args("routes --input 16.txt --max-routes 3")),
+            Ok(Command::Routes(
+                RunOptions {
+                    input: Some(String::from("16.txt")),
+                    ..Default::default()
+                },
+                RoutesOptions { max_routes: 3 }
+            ))
+        );
+        assert_eq!(
+            parse_args(args("routes --format csv")),
+            Err(CliError::UnknownArgument("--format".into()))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_arguments() {
+        assert_eq!(
+            parse_args(args("--part 3")),
+            Err(CliError::InvalidValue("--part".into(), "3".into()))
+        );
+        assert_eq!(
+            parse_args(args("--days 15-10")),
+            Err(CliError::InvalidValue("--days".into(), "15-10".into()))
+        );
+        assert_eq!(
+            parse_args(args("--format yaml")),
+            Err(CliError::InvalidValue("--format".into(), "yaml".into()))
+        );
+        assert_eq!(
+            parse_args(args("--day")),
+            Err(CliError::MissingValue("--day".into()))
+        );
+        assert_eq!(
+            parse_args(args("--fast")),
+            Err(CliError::UnknownArgument("--fast".into()))
+        );
+    }
+}
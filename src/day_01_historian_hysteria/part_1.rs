@@ -1,16 +1,21 @@
-fn process_data(file_path: &str) -> Result<(Vec<u32>, Vec<u32>), Box<dyn std::error::Error>> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
+use crate::parsing::{parse_value, split_at_delimiter, ParseError};
+use crate::solution::{Answer, Solution};
 
+fn process_data(file_content: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
     let mut left_locations: Vec<u32> = Vec::new();
     let mut right_locations: Vec<u32> = Vec::new();
 
-    for line in file_content.lines() {
-        let (left, right) = line
-            .split_once(' ')
-            .expect("Line should contain two numbers separated by at least one space");
+    for (line_index, line) in file_content.lines().enumerate() {
+        let (left, right) = split_at_delimiter(
+            line_index,
+            line,
+            line,
+            " ",
+            "two numbers separated by at least one space",
+        )?;
 
-        let left: u32 = left.trim().parse().expect("Should parse the left number");
-        let right: u32 = right.trim().parse().expect("Should parse the right number");
+        let left: u32 = parse_value(line_index, line, left.trim(), "a location ID")?;
+        let right: u32 = parse_value(line_index, line, right.trim(), "a location ID")?;
 
         left_locations.push(left);
         right_locations.push(right);
@@ -28,12 +33,30 @@ fn find_total_distance_between_locations(left_locations: &[u32], right_locations
         })
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let (mut left_locations, mut right_locations) = process_data("./input/01.txt")?;
-    left_locations.sort_unstable();
-    right_locations.sort_unstable();
-    let result = find_total_distance_between_locations(&left_locations, &right_locations);
-    Ok(format!("Day 1 Historian Hysteria (Part 1): {}.", result))
+pub(crate) struct Part1;
+
+impl Solution for Part1 {
+    type Parsed = (Vec<u32>, Vec<u32>);
+
+    const DAY: u8 = 1;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(
+        &self,
+        (left_locations, right_locations): &Self::Parsed,
+    ) -> Result<Answer, Box<dyn std::error::Error>> {
+        let mut left_locations: Vec<u32> = left_locations.clone();
+        let mut right_locations: Vec<u32> = right_locations.clone();
+        left_locations.sort_unstable();
+        right_locations.sort_unstable();
+        let result: u64 = find_total_distance_between_locations(&left_locations, &right_locations);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -42,7 +65,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() -> Result<(), Box<dyn std::error::Error>> {
-        let (mut left_locations, mut right_locations) = process_data("./test_input/01.txt")?;
+        let file_content = std::fs::read_to_string("./test_input/01.txt").unwrap();
+        let (mut left_locations, mut right_locations) = process_data(&file_content).unwrap();
         left_locations.sort_unstable();
         right_locations.sort_unstable();
         let result = find_total_distance_between_locations(&left_locations, &right_locations);
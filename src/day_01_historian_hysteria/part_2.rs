@@ -1,18 +1,22 @@
+use crate::parsing::{parse_value, split_at_delimiter, ParseError};
+use crate::solution::{Answer, Solution};
 use std::collections::HashMap;
 
-fn process_data(file_path: &str) -> Result<(Vec<u32>, Vec<u32>), Box<dyn std::error::Error>> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
-
+fn process_data(file_content: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
     let mut left_locations: Vec<u32> = Vec::new();
     let mut right_locations: Vec<u32> = Vec::new();
 
-    for line in file_content.lines() {
-        let (left, right) = line
-            .split_once(' ')
-            .expect("Line should contain two numbers separated by at least one space");
+    for (line_index, line) in file_content.lines().enumerate() {
+        let (left, right) = split_at_delimiter(
+            line_index,
+            line,
+            line,
+            " ",
+            "two numbers separated by at least one space",
+        )?;
 
-        let left: u32 = left.trim().parse().expect("Should parse the left number");
-        let right: u32 = right.trim().parse().expect("Should parse the right number");
+        let left: u32 = parse_value(line_index, line, left.trim(), "a location ID")?;
+        let right: u32 = parse_value(line_index, line, right.trim(), "a location ID")?;
 
         left_locations.push(left);
         right_locations.push(right);
@@ -39,10 +43,26 @@ fn calculate_similarity_score(left_locations: &[u32], right_locations: &[u32]) -
         .sum()
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let (left_locations, right_locations) = process_data("./input/01.txt")?;
-    let result = calculate_similarity_score(&left_locations, &right_locations);
-    Ok(format!("Day 1 Historian Hysteria (Part 2): {}.", result))
+pub(crate) struct Part2;
+
+impl Solution for Part2 {
+    type Parsed = (Vec<u32>, Vec<u32>);
+
+    const DAY: u8 = 1;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(
+        &self,
+        (left_locations, right_locations): &Self::Parsed,
+    ) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: u64 = calculate_similarity_score(left_locations, right_locations);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -51,7 +71,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() -> Result<(), Box<dyn std::error::Error>> {
-        let (left_locations, right_locations) = process_data("./test_input/01.txt")?;
+        let file_content = std::fs::read_to_string("./test_input/01.txt").unwrap();
+        let (left_locations, right_locations) = process_data(&file_content).unwrap();
         let result = calculate_similarity_score(&left_locations, &right_locations);
         assert_eq!(result, 31);
         Ok(())
@@ -1,13 +1,14 @@
+use crate::parsing::{parse_value, ParseError};
+use crate::solution::{Answer, Solution};
 use std::cmp::Ordering;
 
-fn process_data(file_path: &str) -> Vec<Vec<u8>> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
-
+fn process_data(file_content: &str) -> Result<Vec<Vec<u8>>, ParseError> {
     file_content
         .lines()
-        .map(|line| {
+        .enumerate()
+        .map(|(line_index, line)| {
             line.split_whitespace()
-                .map(|number| number.parse::<u8>().expect("Should parse the number"))
+                .map(|number| parse_value(line_index, line, number, "a level between 0 and 255"))
                 .collect()
         })
         .collect()
@@ -36,13 +37,26 @@ fn is_report_safe(report: &[u8]) -> bool {
     true
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let reports: Vec<Vec<u8>> = process_data("./input/02.txt");
-    let result: usize = reports
-        .into_iter()
-        .filter(|report| is_report_safe(report))
-        .count();
-    Ok(format!("Day 2 Red-Nosed Reports (Part 1): {}.", result))
+pub(crate) struct Part1;
+
+impl Solution for Part1 {
+    type Parsed = Vec<Vec<u8>>;
+
+    const DAY: u8 = 2;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, reports: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: usize = reports
+            .iter()
+            .filter(|report| is_report_safe(report))
+            .count();
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -51,7 +65,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() -> Result<(), Box<dyn std::error::Error>> {
-        let reports: Vec<Vec<u8>> = process_data("./test_input/02.txt");
+        let file_content = std::fs::read_to_string("./test_input/02.txt").unwrap();
+        let reports: Vec<Vec<u8>> = process_data(&file_content).unwrap();
         let result: u64 = reports
             .into_iter()
             .map(|report| is_report_safe(&report) as u64)
@@ -1,13 +1,14 @@
+use crate::parsing::{parse_value, ParseError};
+use crate::solution::{Answer, Solution};
 use std::cmp::Ordering;
 
-fn process_data(file_path: &str) -> Vec<Vec<u8>> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
-
+fn process_data(file_content: &str) -> Result<Vec<Vec<u8>>, ParseError> {
     file_content
         .lines()
-        .map(|line| {
+        .enumerate()
+        .map(|(line_index, line)| {
             line.split_whitespace()
-                .map(|number| number.parse::<u8>().expect("Should parse the number"))
+                .map(|number| parse_value(line_index, line, number, "a level between 0 and 255"))
                 .collect()
         })
         .collect()
@@ -63,10 +64,23 @@ fn calculate_number_of_safe_levels(reports: &Vec<Vec<u8>>) -> u64 {
     result
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let reports: Vec<Vec<u8>> = process_data("./input/02.txt");
-    let result = calculate_number_of_safe_levels(&reports);
-    Ok(format!("Day 2 Red-Nosed Reports (Part 2): {}.", result))
+pub(crate) struct Part2;
+
+impl Solution for Part2 {
+    type Parsed = Vec<Vec<u8>>;
+
+    const DAY: u8 = 2;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, reports: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: u64 = calculate_number_of_safe_levels(reports);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -75,7 +89,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() -> Result<(), Box<dyn std::error::Error>> {
-        let reports: Vec<Vec<u8>> = process_data("./test_input/02.txt");
+        let file_content = std::fs::read_to_string("./test_input/02.txt").unwrap();
+        let reports: Vec<Vec<u8>> = process_data(&file_content).unwrap();
         let result = calculate_number_of_safe_levels(&reports);
         assert_eq!(result, 4);
         Ok(())
@@ -1,6 +1,5 @@
-fn get_data(file_path: &str) -> String {
-    std::fs::read_to_string(file_path).expect("Should open a file")
-}
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
 
 fn find_valid_multiplication_operations(corrupted_data: &str) -> Vec<(u32, u32)> {
     let mut multiplicands_and_multipliers: Vec<(u32, u32)> = Vec::new();
@@ -31,11 +30,24 @@ fn process_multiplication_operations(multiplication_operations: &[(u32, u32)]) -
         })
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let corrupted_data: String = get_data("./input/03.txt");
-    let multiplications: Vec<(u32, u32)> = find_valid_multiplication_operations(&corrupted_data);
-    let result: u64 = process_multiplication_operations(&multiplications);
-    Ok(format!("Day 3 Mull It Over (Part 1): {}.", result))
+pub(crate) struct Part1;
+
+impl Solution for Part1 {
+    type Parsed = String;
+
+    const DAY: u8 = 3;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Mull It Over";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input.to_owned())
+    }
+
+    fn solve(&self, corrupted_data: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let multiplications: Vec<(u32, u32)> = find_valid_multiplication_operations(corrupted_data);
+        let result: u64 = process_multiplication_operations(&multiplications);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -44,7 +56,7 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let corrupted_data = get_data("./test_input/03_01.txt");
+        let corrupted_data = std::fs::read_to_string("./test_input/03_01.txt").unwrap();
         let multiplications = find_valid_multiplication_operations(&corrupted_data);
         let result = process_multiplication_operations(&multiplications);
         assert_eq!(result, 161);
@@ -1,6 +1,5 @@
-fn get_data(file_path: &str) -> String {
-    std::fs::read_to_string(file_path).expect("Should open a file")
-}
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
 
 fn process_do_and_dont_operations(corrupted_data: &str) -> Vec<&str> {
     let mut corrupted_data = corrupted_data;
@@ -53,12 +52,26 @@ fn process_multiplication_operations(multiplication_operations: &[(u32, u32)]) -
         .sum()
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let corrupted_data: String = get_data("./input/03.txt");
-    let corrupted_data: Vec<&str> = process_do_and_dont_operations(&corrupted_data);
-    let multiplications: Vec<(u32, u32)> = find_valid_multiplication_operations(&corrupted_data);
-    let result: u64 = process_multiplication_operations(&multiplications);
-    Ok(format!("Day 3 Mull It Over (Part 2): {}.", result))
+pub(crate) struct Part2;
+
+impl Solution for Part2 {
+    type Parsed = String;
+
+    const DAY: u8 = 3;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Mull It Over";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input.to_owned())
+    }
+
+    fn solve(&self, corrupted_data: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let corrupted_data: Vec<&str> = process_do_and_dont_operations(corrupted_data);
+        let multiplications: Vec<(u32, u32)> =
+            find_valid_multiplication_operations(&corrupted_data);
+        let result: u64 = process_multiplication_operations(&multiplications);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -67,7 +80,7 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let corrupted_data = get_data("./test_input/03_02.txt");
+        let corrupted_data = std::fs::read_to_string("./test_input/03_02.txt").unwrap();
         let corrupted_data = process_do_and_dont_operations(&corrupted_data);
         let multiplications = find_valid_multiplication_operations(&corrupted_data);
         let result = process_multiplication_operations(&multiplications);
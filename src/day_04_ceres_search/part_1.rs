@@ -1,51 +1,55 @@
-fn process_data(file_path: &str) -> Vec<Vec<char>> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
+use crate::grid::Grid;
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
 
-    file_content
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect()
+fn process_data(file_content: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(
+        file_content,
+        |char| "XMAS".contains(char).then_some(char),
+        "one of 'X', 'M', 'A', 'S'",
+    )
 }
 
-fn is_in_grid(x: i64, y: i64, height: usize, width: usize) -> bool {
-    x >= 0 && y >= 0 && x < width as i64 && y < height as i64
+fn count_xmas_in_line(line: &[char]) -> u64 {
+    line.windows(4)
+        .filter(|&window| window == ['X', 'M', 'A', 'S'] || window == ['S', 'A', 'M', 'X'])
+        .count() as u64
 }
 
-fn get_neighbour_deltas() -> Vec<(i64, i64)> {
-    (-1..=1)
-        .flat_map(|x| (-1..=1).map(move |y| (x, y)))
-        .filter(|&(x, y)| x != 0 || y != 0)
-        .collect()
+fn find_xmas(letters: &Grid<char>) -> u64 {
+    let rows = letters.rows().map(|row| row.to_vec());
+    let columns = letters.columns().map(|column| column.copied().collect());
+    let diagonals = letters
+        .diagonals()
+        .map(|diagonal| diagonal.copied().collect());
+    let anti_diagonals = letters
+        .anti_diagonals()
+        .map(|diagonal| diagonal.copied().collect());
+
+    rows.chain(columns)
+        .chain(diagonals)
+        .chain(anti_diagonals)
+        .map(|line: Vec<char>| count_xmas_in_line(&line))
+        .sum()
 }
 
-fn find_xmas(letters: &[Vec<char>]) -> u64 {
-    let mut result: u64 = 0;
-    let (height, width) = (letters.len(), letters[0].len());
-
-    for (y, line) in letters.iter().enumerate() {
-        for (x, &char) in line.iter().enumerate() {
-            if char == 'X' {
-                for (dx, dy) in get_neighbour_deltas() {
-                    let (mut nx, mut ny) = (x as i64, y as i64);
-
-                    result += ['M', 'A', 'S'].iter().all(|&target| {
-                        (nx, ny) = (nx + dx, ny + dy);
-
-                        is_in_grid(nx, ny, height, width)
-                            && letters[ny as usize][nx as usize] == target
-                    }) as u64;
-                }
-            }
-        }
-    }
+pub(crate) struct Part1;
 
-    result
-}
+impl Solution for Part1 {
+    type Parsed = Grid<char>;
+
+    const DAY: u8 = 4;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Ceres Search";
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let letters: Vec<Vec<char>> = process_data("./input/04.txt");
-    let result: u64 = find_xmas(&letters);
-    Ok(format!("Day 4 Ceres Search (Part 1): {}.", result))
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, letters: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: u64 = find_xmas(letters);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -54,7 +58,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let letters = process_data("./test_input/04.txt");
+        let file_content = std::fs::read_to_string("./test_input/04.txt").unwrap();
+        let letters = process_data(&file_content).unwrap();
         let result = find_xmas(&letters);
         assert_eq!(result, 18);
     }
@@ -1,54 +1,54 @@
-fn process_data(file_path: &str) -> Vec<Vec<char>> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
+use crate::direction::Direction8;
+use crate::grid::{Grid, Point};
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
 
-    file_content
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect()
+fn process_data(file_content: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(
+        file_content,
+        |char| "XMAS".contains(char).then_some(char),
+        "one of 'X', 'M', 'A', 'S'",
+    )
 }
 
-fn get_neighbours(x: usize, y: usize, height: usize, width: usize) -> Option<[[usize; 2]; 4]> {
-    let neighbours = [
-        [x + 1, y.wrapping_sub(1)],
-        [x + 1, y + 1],
-        [x.wrapping_sub(1), y + 1],
-        [x.wrapping_sub(1), y.wrapping_sub(1)],
-    ];
+fn find_x_mas(letters: &Grid<char>) -> u64 {
+    let check_diagonal = |point: Point, direction: Direction8| {
+        matches!(
+            (
+                letters.get(point + direction.delta()),
+                letters.get(point + direction.opposite().delta())
+            ),
+            (Some('M'), Some('S')) | (Some('S'), Some('M'))
+        )
+    };
 
-    neighbours
+    letters
         .iter()
-        .all(|&[nx, ny]| nx < width && ny < height)
-        .then_some(neighbours)
+        .filter(|&(_, &char)| char == 'A')
+        .filter(|&(point, _)| {
+            check_diagonal(point, Direction8::NorthEast)
+                && check_diagonal(point, Direction8::SouthEast)
+        })
+        .count() as u64
 }
 
-fn find_x_mas(letters: &[Vec<char>]) -> u64 {
-    let check_pair = |[a_x, a_y]: [usize; 2], [b_x, b_y]: [usize; 2]| {
-        (letters[a_y][a_x] == 'M' && letters[b_y][b_x] == 'S')
-            || (letters[a_y][a_x] == 'S' && letters[b_y][b_x] == 'M')
-    };
+pub(crate) struct Part2;
 
-    let (height, width) = (letters.len(), letters[0].len());
-    let mut result = 0;
+impl Solution for Part2 {
+    type Parsed = Grid<char>;
 
-    for (y, line) in letters.iter().enumerate() {
-        for (x, &char) in line.iter().enumerate() {
-            if char == 'A' {
-                if let Some(corner_neighbours) = get_neighbours(x, y, height, width) {
-                    result += (check_pair(corner_neighbours[0], corner_neighbours[2])
-                        && check_pair(corner_neighbours[1], corner_neighbours[3]))
-                        as u64;
-                }
-            }
-        }
-    }
+    const DAY: u8 = 4;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Ceres Search";
 
-    result
-}
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let letters: Vec<Vec<char>> = process_data("./input/04.txt");
-    let result: u64 = find_x_mas(&letters);
-    Ok(format!("Day 4 Ceres Search (Part 2): {}.", result))
+    fn solve(&self, letters: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: u64 = find_x_mas(letters);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -57,7 +57,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let letters: Vec<Vec<char>> = process_data("./test_input/04.txt");
+        let file_content = std::fs::read_to_string("./test_input/04.txt").unwrap();
+        let letters: Grid<char> = process_data(&file_content).unwrap();
         let result: u64 = find_x_mas(&letters);
         assert_eq!(result, 9);
     }
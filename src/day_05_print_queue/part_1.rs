@@ -1,30 +1,56 @@
-use std::collections::HashMap;
+use crate::parsing::{parse_value, split_at_delimiter, ParseError};
+use crate::solution::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
 
-fn process_data(file_path: &str) -> (Vec<(u8, u8)>, Vec<Vec<u8>>) {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
-    let mut lines = file_content.lines();
+type RulesAndUpdates = (Vec<(u8, u8)>, Vec<Vec<u8>>);
+
+fn process_data(file_content: &str) -> Result<RulesAndUpdates, ParseError> {
+    let mut lines = file_content.lines().enumerate();
 
     let page_ordering_rules: Vec<(u8, u8)> = lines
         .by_ref()
-        .take_while(|line| !line.is_empty())
-        .map(|line| line.split_once("|").expect("Should contain '|' character"))
-        .map(|(num_1, num_2)| {
-            (
-                num_1.parse::<u8>().expect("Should be a valid u8 number"),
-                num_2.parse::<u8>().expect("Should be a valid u8 number"),
-            )
+        .take_while(|(_, line)| !line.is_empty())
+        .map(|(line_index, line)| {
+            let (num_1, num_2) = split_at_delimiter(
+                line_index,
+                line,
+                line,
+                "|",
+                "two page numbers separated by '|'",
+            )?;
+
+            Ok((
+                parse_value(line_index, line, num_1, "a page number")?,
+                parse_value(line_index, line, num_2, "a page number")?,
+            ))
         })
+        .collect::<Result<_, ParseError>>()?;
+    let pages_with_rules: HashSet<u8> = page_ordering_rules
+        .iter()
+        .flat_map(|&(num_1, num_2)| [num_1, num_2])
         .collect();
 
     let updates: Vec<Vec<u8>> = lines
-        .map(|line| {
+        .map(|(line_index, line)| {
             line.split(",")
-                .map(|num| num.parse::<u8>().expect("Should be a valid u8 number"))
+                .map(|num| {
+                    let page: u8 = parse_value(line_index, line, num, "a page number")?;
+
+                    match pages_with_rules.contains(&page) {
+                        true => Ok(page),
+                        false => Err(ParseError::invalid_value(
+                            line_index,
+                            line,
+                            num,
+                            "a page number with an ordering rule",
+                        )),
+                    }
+                })
                 .collect()
         })
-        .collect();
+        .collect::<Result<_, ParseError>>()?;
 
-    (page_ordering_rules, updates)
+    Ok((page_ordering_rules, updates))
 }
 
 fn build_page_ordering_map(page_ordering_rules: &[(u8, u8)]) -> HashMap<u8, Vec<u8>> {
@@ -59,11 +85,27 @@ fn sum_valid_updates_middle_page(updates: &[Vec<u8>], ordering_map: &HashMap<u8,
         .sum()
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let (ordering_rules, updates): (Vec<(u8, u8)>, Vec<Vec<u8>>) = process_data("./input/05.txt");
-    let page_ordering_map: HashMap<u8, Vec<u8>> = build_page_ordering_map(&ordering_rules);
-    let result: u32 = sum_valid_updates_middle_page(&updates, &page_ordering_map);
-    Ok(format!("Day 5 Print Queue (Part 1): {}.", result))
+pub(crate) struct Part1;
+
+impl Solution for Part1 {
+    type Parsed = RulesAndUpdates;
+
+    const DAY: u8 = 5;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Print Queue";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(
+        &self,
+        (ordering_rules, updates): &Self::Parsed,
+    ) -> Result<Answer, Box<dyn std::error::Error>> {
+        let page_ordering_map: HashMap<u8, Vec<u8>> = build_page_ordering_map(ordering_rules);
+        let result: u32 = sum_valid_updates_middle_page(updates, &page_ordering_map);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -72,9 +114,19 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let (page_ordering_rules, updates) = process_data("./test_input/05.txt");
+        let file_content = std::fs::read_to_string("./test_input/05.txt").unwrap();
+        let (page_ordering_rules, updates) = process_data(&file_content).unwrap();
         let page_ordering_map = build_page_ordering_map(&page_ordering_rules);
         let result = sum_valid_updates_middle_page(&updates, &page_ordering_map);
         assert_eq!(result, 143);
     }
+
+    #[test]
+    fn reject_page_without_ordering_rule() {
+        let error = process_data("1|2\n\n1,2,3").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 3, column 5: invalid value '3', expected a page number with an ordering rule"
+        );
+    }
 }
@@ -1,2 +1,9 @@
+use crate::solution::Registry;
+
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+
+pub(crate) fn register(registry: &mut Registry) {
+    registry.register(part_1::Part1);
+    registry.register(part_2::Part2);
+}
@@ -1,54 +1,80 @@
+use crate::direction::Direction;
+use crate::grid::{Grid, Point};
+use crate::parsing::ParseError;
+use crate::render::{Renderer, Style, Tile};
+use crate::solution::{Answer, Solution};
 use std::collections::HashSet;
+use std::io;
 
-fn process_data(file_path: &str) -> Vec<Vec<char>> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
-
-    file_content
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect()
-}
-
-fn find_starting_position(map: &[Vec<char>]) -> Option<(i32, i32)> {
-    for (y, row) in map.iter().enumerate() {
-        if let Some(x) = row.iter().position(|&c| c == '^') {
-            return Some((x as i32, y as i32));
-        }
-    }
-
-    None
+fn process_data(file_content: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(
+        file_content,
+        |char| ".#^".contains(char).then_some(char),
+        "one of '.', '#', '^'",
+    )
 }
 
-fn simulate_guard_movement(map: &[Vec<char>], (x, y): (i32, i32)) -> usize {
-    let mut directions = [(0, -1), (1, 0), (0, 1), (-1, 0)].iter().cycle();
-    let (mut dx, mut dy): &(i32, i32) = directions.next().expect("Should produce (dx, dy)");
-    let mut unique_steps: HashSet<(i32, i32)> = HashSet::from([(x, y)]);
-    let (mut x, mut y): (i32, i32) = (x, y);
+fn find_visited_positions(map: &Grid<char>, start: Point) -> HashSet<Point> {
+    let mut direction: Direction = Direction::North;
+    let mut unique_steps: HashSet<Point> = HashSet::from([start]);
+    let mut position: Point = start;
 
     loop {
-        let (nx, ny) = (x + dx, y + dy);
+        let next: Point = position + direction.delta();
 
-        let cell = match map.get(ny as usize).and_then(|row| row.get(nx as usize)) {
+        let cell = match map.get(next) {
             Some(&c) => c,
-            None => return unique_steps.len(),
+            None => return unique_steps,
         };
 
         match cell {
-            '#' => (dx, dy) = *directions.next().expect("Should produce (dx, dy)"),
+            '#' => direction = direction.rotate_right(),
             '.' | '^' => {
-                unique_steps.insert((nx, ny));
-                (x, y) = (nx, ny);
+                unique_steps.insert(next);
+                position = next;
             }
             c => panic!("Invalid symbol on the map: '{}'", c),
         }
     }
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let map: Vec<Vec<char>> = process_data("./input/06.txt");
-    let start: (i32, i32) = find_starting_position(&map).ok_or("Starting position not found")?;
-    let result: usize = simulate_guard_movement(&map, start);
-    Ok(format!("Day 6 Guard Gallivant (Part 1): {}.", result))
+fn simulate_guard_movement(map: &Grid<char>, start: Point) -> usize {
+    find_visited_positions(map, start).len()
+}
+
+pub(crate) struct Part1;
+
+impl Solution for Part1 {
+    type Parsed = (Grid<char>, Point);
+
+    const DAY: u8 = 6;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let map: Grid<char> = process_data(input)?;
+        let start: Point = map
+            .find(&'^')
+            .ok_or(ParseError::missing_element("a starting position '^'"))?;
+        Ok((map, start))
+    }
+
+    fn solve(&self, (map, start): &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: usize = simulate_guard_movement(map, *start);
+        Ok(Answer::from(result))
+    }
+
+    fn visualize(&self, (map, start): &Self::Parsed, renderer: &mut Renderer) -> io::Result<()> {
+        let mut frame: Grid<Tile> = map.map(|&symbol| Tile::from_symbol(symbol));
+
+        for position in find_visited_positions(map, *start) {
+            if position != *start {
+                frame[position] = Tile::new('X', Style::Visited);
+            }
+        }
+
+        renderer.render(&frame)
+    }
 }
 
 #[cfg(test)]
@@ -57,8 +83,9 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let map = process_data("./test_input/06.txt");
-        let start = find_starting_position(&map).unwrap();
+        let file_content = std::fs::read_to_string("./test_input/06.txt").unwrap();
+        let map = process_data(&file_content).unwrap();
+        let start = map.find(&'^').unwrap();
         let result = simulate_guard_movement(&map, start);
         assert_eq!(result, 41);
     }
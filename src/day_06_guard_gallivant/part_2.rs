@@ -1,43 +1,35 @@
+use crate::direction::Direction;
+use crate::grid::{Grid, Point};
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
 use std::collections::HashSet;
 
-fn process_data(file_path: &str) -> Vec<Vec<char>> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
-
-    file_content
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect()
-}
-
-fn find_starting_position(map: &[Vec<char>]) -> Option<(i32, i32)> {
-    for (y, row) in map.iter().enumerate() {
-        if let Some(x) = row.iter().position(|&c| c == '^') {
-            return Some((x as i32, y as i32));
-        }
-    }
-
-    None
+fn process_data(file_content: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(
+        file_content,
+        |char| ".#^".contains(char).then_some(char),
+        "one of '.', '#', '^'",
+    )
 }
 
-fn record_guard_movement(map: &[Vec<char>], (x, y): (i32, i32)) -> HashSet<(i32, i32)> {
-    let mut directions = [(0, -1), (1, 0), (0, 1), (-1, 0)].iter().cycle();
-    let (mut dx, mut dy): &(i32, i32) = directions.next().expect("Should produce (dx, dy)");
-    let mut unique_steps: HashSet<(i32, i32)> = HashSet::from([(x, y)]);
-    let (mut x, mut y): (i32, i32) = (x, y);
+fn record_guard_movement(map: &Grid<char>, start: Point) -> HashSet<Point> {
+    let mut direction: Direction = Direction::North;
+    let mut unique_steps: HashSet<Point> = HashSet::from([start]);
+    let mut position: Point = start;
 
     loop {
-        let (nx, ny) = (x + dx, y + dy);
+        let next: Point = position + direction.delta();
 
-        let cell = match map.get(ny as usize).and_then(|row| row.get(nx as usize)) {
+        let cell = match map.get(next) {
             Some(&c) => c,
             None => return unique_steps,
         };
 
         match cell {
-            '#' => (dx, dy) = *directions.next().expect("Should produce (dx, dy)"),
+            '#' => direction = direction.rotate_right(),
             '.' | '^' => {
-                unique_steps.insert((nx, ny));
-                (x, y) = (nx, ny);
+                unique_steps.insert(next);
+                position = next;
             }
             c => panic!("Invalid symbol on the map: '{}'", c),
         }
@@ -45,41 +37,40 @@ fn record_guard_movement(map: &[Vec<char>], (x, y): (i32, i32)) -> HashSet<(i32,
 }
 
 fn simulate_inserting_an_obstacle(
-    map: &[Vec<char>],
-    (x, y): (i32, i32),
-    movement_path: &HashSet<(i32, i32)>,
+    map: &Grid<char>,
+    start: Point,
+    movement_path: &HashSet<Point>,
 ) -> u64 {
     let mut infinite_loop_counter: u64 = 0;
 
     for obstacle in movement_path {
-        let mut stop_conditions: Vec<((i32, i32), (i32, i32))> = Vec::new();
-        let mut directions = [(0, -1), (1, 0), (0, 1), (-1, 0)].iter().cycle();
-        let (mut dx, mut dy): &(i32, i32) = directions.next().expect("Should produce (dx, dy)");
-        let (mut x, mut y): (i32, i32) = (x, y);
+        let mut stop_conditions: Vec<(Point, Direction)> = Vec::new();
+        let mut direction: Direction = Direction::North;
+        let mut position: Point = start;
 
         loop {
-            let (nx, ny) = (x + dx, y + dy);
+            let next: Point = position + direction.delta();
 
-            let mut cell = match map.get(ny as usize).and_then(|row| row.get(nx as usize)) {
+            let mut cell = match map.get(next) {
                 Some(&c) => c,
                 None => break,
             };
 
-            if (nx, ny) == *obstacle {
+            if next == *obstacle {
                 cell = 'O';
             }
 
             match cell {
                 '#' | 'O' => {
-                    if stop_conditions.contains(&((nx, ny), (dx, dy))) {
+                    if stop_conditions.contains(&(next, direction)) {
                         infinite_loop_counter += 1;
                         break;
                     }
 
-                    stop_conditions.push(((nx, ny), (dx, dy)));
-                    (dx, dy) = *directions.next().expect("Should produce (dx, dy)");
+                    stop_conditions.push((next, direction));
+                    direction = direction.rotate_right();
                 }
-                '.' | '^' => (x, y) = (nx, ny),
+                '.' | '^' => position = next,
                 c => panic!("Invalid symbol on the map: '{}'", c),
             }
         }
@@ -88,12 +79,28 @@ fn simulate_inserting_an_obstacle(
     infinite_loop_counter
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let map: Vec<Vec<char>> = process_data("./input/06.txt");
-    let start: (i32, i32) = find_starting_position(&map).ok_or("Starting position not found")?;
-    let guard_movement: HashSet<(i32, i32)> = record_guard_movement(&map, start);
-    let result: u64 = simulate_inserting_an_obstacle(&map, start, &guard_movement);
-    Ok(format!("Day 6 Guard Gallivant (Part 2): {}.", result))
+pub(crate) struct Part2;
+
+impl Solution for Part2 {
+    type Parsed = (Grid<char>, Point);
+
+    const DAY: u8 = 6;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let map: Grid<char> = process_data(input)?;
+        let start: Point = map
+            .find(&'^')
+            .ok_or(ParseError::missing_element("a starting position '^'"))?;
+        Ok((map, start))
+    }
+
+    fn solve(&self, (map, start): &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let guard_movement: HashSet<Point> = record_guard_movement(map, *start);
+        let result: u64 = simulate_inserting_an_obstacle(map, *start, &guard_movement);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -102,8 +109,9 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let map = process_data("./test_input/06.txt");
-        let start = find_starting_position(&map).unwrap();
+        let file_content = std::fs::read_to_string("./test_input/06.txt").unwrap();
+        let map = process_data(&file_content).unwrap();
+        let start = map.find(&'^').unwrap();
         let guard_movement = record_guard_movement(&map, start);
         let result = simulate_inserting_an_obstacle(&map, start, &guard_movement);
         assert_eq!(result, 6);
@@ -1,12 +1,22 @@
-fn process_data(file_path: &str) -> Vec<Vec<u64>> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
+use crate::parsing::{parse_value, split_at_delimiter, ParseError};
+use crate::solution::{Answer, Solution};
 
+fn process_data(file_content: &str) -> Result<Vec<Vec<u64>>, ParseError> {
     file_content
         .lines()
-        .map(|line| {
-            line.replace(":", "")
-                .split(" ")
-                .map(|num| num.parse().expect("Should be a valid u32 number"))
+        .enumerate()
+        .map(|(line_index, line)| {
+            let (test_value, numbers) = split_at_delimiter(
+                line_index,
+                line,
+                line,
+                ": ",
+                "a test value followed by ': ' and numbers",
+            )?;
+
+            std::iter::once(test_value)
+                .chain(numbers.split(" "))
+                .map(|num| parse_value(line_index, line, num, "a valid u64 number"))
                 .collect()
         })
         .collect()
@@ -53,10 +63,23 @@ fn sum_valid_operations(equations: &[Vec<u64>]) -> u64 {
         .sum()
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let equations = process_data("./input/07.txt");
-    let result: u64 = sum_valid_operations(&equations);
-    Ok(format!("Day 7 Bridge Repair (Part 2): {}.", result))
+pub(crate) struct Part2;
+
+impl Solution for Part2 {
+    type Parsed = Vec<Vec<u64>>;
+
+    const DAY: u8 = 7;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Bridge Repair";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, equations: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: u64 = sum_valid_operations(equations);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -65,7 +88,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let equations = process_data("./test_input/07.txt");
+        let file_content = std::fs::read_to_string("./test_input/07.txt").unwrap();
+        let equations = process_data(&file_content).unwrap();
         let result: u64 = sum_valid_operations(&equations);
         assert_eq!(result, 11387);
     }
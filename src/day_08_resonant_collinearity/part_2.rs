@@ -1,41 +1,41 @@
+use crate::grid::{Grid, Point};
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
 use std::collections::{HashMap, HashSet};
 
-fn process_data(file_path: &str) -> Vec<Vec<char>> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
-
-    file_content
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect()
+fn process_data(file_content: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(
+        file_content,
+        |char| (char == '.' || char.is_ascii_alphanumeric()).then_some(char),
+        "'.' or an antenna frequency",
+    )
 }
 
-fn group_by_same_frequency(antenna_map: &[Vec<char>]) -> HashMap<char, Vec<(i32, i32)>> {
-    let mut hash_map: HashMap<char, Vec<(i32, i32)>> = HashMap::new();
+fn group_by_same_frequency(antenna_map: &Grid<char>) -> HashMap<char, Vec<Point>> {
+    let mut hash_map: HashMap<char, Vec<Point>> = HashMap::new();
 
-    for (y, row) in antenna_map.iter().enumerate() {
-        for (x, &char) in row.iter().enumerate() {
-            if char != '.' {
-                hash_map.entry(char).or_default().push((x as i32, y as i32));
-            }
+    for (point, &char) in antenna_map.iter() {
+        if char != '.' {
+            hash_map.entry(char).or_default().push(point);
         }
     }
 
     hash_map
 }
 
-fn count_antinodes(map: &HashMap<char, Vec<(i32, i32)>>, height: i32, width: i32) -> usize {
-    let mut antinodes: HashSet<(i32, i32)> = HashSet::new();
+fn count_antinodes(map: &HashMap<char, Vec<Point>>, antenna_map: &Grid<char>) -> usize {
+    let mut antinodes: HashSet<Point> = HashSet::new();
 
     for antenna_positions in map.values() {
-        for &(x1, y1) in antenna_positions {
-            for &(x2, y2) in antenna_positions {
-                if (x1, y1) != (x2, y2) {
-                    let (dx, dy) = (x1 - x2, y1 - y2);
-                    let (mut nx, mut ny) = (x1, y1);
+        for &first in antenna_positions {
+            for &second in antenna_positions {
+                if first != second {
+                    let delta: Point = first - second;
+                    let mut antinode: Point = first;
 
-                    while nx >= 0 && ny >= 0 && nx < width && ny < height {
-                        antinodes.insert((nx, ny));
-                        (nx, ny) = (nx + dx, ny + dy);
+                    while antenna_map.contains(antinode) {
+                        antinodes.insert(antinode);
+                        antinode = antinode + delta;
                     }
                 }
             }
@@ -45,12 +45,24 @@ fn count_antinodes(map: &HashMap<char, Vec<(i32, i32)>>, height: i32, width: i32
     antinodes.len()
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let antennas: Vec<Vec<char>> = process_data("./input/08.txt");
-    let (height, width): (i32, i32) = (antennas.len() as i32, antennas[0].len() as i32);
-    let antenna_map: HashMap<char, Vec<(i32, i32)>> = group_by_same_frequency(&antennas);
-    let result: usize = count_antinodes(&antenna_map, height, width);
-    Ok(format!("Day 8 Resonant Collinearity (Part 2): {}.", result))
+pub(crate) struct Part2;
+
+impl Solution for Part2 {
+    type Parsed = Grid<char>;
+
+    const DAY: u8 = 8;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Resonant Collinearity";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, antennas: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let antenna_map: HashMap<char, Vec<Point>> = group_by_same_frequency(antennas);
+        let result: usize = count_antinodes(&antenna_map, antennas);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -59,10 +71,10 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let antennas = process_data("./test_input/08.txt");
-        let (height, width) = (antennas.len() as i32, antennas[0].len() as i32);
+        let file_content = std::fs::read_to_string("./test_input/08.txt").unwrap();
+        let antennas = process_data(&file_content).unwrap();
         let antenna_map = group_by_same_frequency(&antennas);
-        let result = count_antinodes(&antenna_map, height, width);
+        let result = count_antinodes(&antenna_map, &antennas);
         assert_eq!(result, 34);
     }
 }
@@ -1,10 +1,18 @@
-fn process_data(file_path: &str) -> Vec<u32> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
+use crate::parsing::{parse_digits, ParseError};
+use crate::solution::{Answer, Solution};
 
-    file_content
-        .chars()
-        .map(|c| c.to_digit(10).expect("Should be a digit between 0 and 9"))
-        .collect()
+fn process_data(file_content: &str) -> Result<Vec<u32>, ParseError> {
+    let mut disk_map: Vec<u32> = Vec::new();
+
+    for (line_index, line) in file_content.lines().enumerate() {
+        disk_map.extend(parse_digits(line_index, line)?);
+    }
+
+    if disk_map.is_empty() {
+        return Err(ParseError::missing_element("a disk map"));
+    }
+
+    Ok(disk_map)
 }
 
 fn unpack_information(disk_map: &[u32]) -> Vec<Option<u32>> {
@@ -24,22 +32,26 @@ fn unpack_information(disk_map: &[u32]) -> Vec<Option<u32>> {
 
 fn fragment_unpacked_information(unpacked_information: &mut [Option<u32>]) {
     let mut free_space_index: usize = 0;
-    let mut occupied_space_index: usize = unpacked_information.len() - 1;
+    let mut occupied_space_end: usize = unpacked_information.len();
 
     loop {
-        while unpacked_information[free_space_index].is_some() {
+        while free_space_index < occupied_space_end
+            && unpacked_information[free_space_index].is_some()
+        {
             free_space_index += 1;
         }
 
-        while unpacked_information[occupied_space_index].is_none() {
-            occupied_space_index -= 1;
+        while occupied_space_end > free_space_index
+            && unpacked_information[occupied_space_end - 1].is_none()
+        {
+            occupied_space_end -= 1;
         }
 
-        if free_space_index > occupied_space_index {
+        if free_space_index >= occupied_space_end {
             break;
         }
 
-        unpacked_information.swap(free_space_index, occupied_space_index);
+        unpacked_information.swap(free_space_index, occupied_space_end - 1);
     }
 }
 
@@ -51,21 +63,59 @@ fn calculate_checksum(fragmented_information: &[Option<u32>]) -> u64 {
         .sum()
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let dense_format_disk_map: Vec<u32> = process_data("./input/09.txt");
-    let mut unpacked_information: Vec<Option<u32>> = unpack_information(&dense_format_disk_map);
-    fragment_unpacked_information(&mut unpacked_information);
-    let result: u64 = calculate_checksum(&unpacked_information);
-    Ok(format!("Day 9 Disk Fragmenter (Part 1): {}.", result))
+pub(crate) struct Part1;
+
+impl Solution for Part1 {
+    type Parsed = Vec<u32>;
+
+    const DAY: u8 = 9;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(
+        &self,
+        dense_format_disk_map: &Self::Parsed,
+    ) -> Result<Answer, Box<dyn std::error::Error>> {
+        let mut unpacked_information: Vec<Option<u32>> = unpack_information(dense_format_disk_map);
+        fragment_unpacked_information(&mut unpacked_information);
+        let result: u64 = calculate_checksum(&unpacked_information);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::day_09_disk_fragmenter::part_1::*;
 
+    #[test]
+    fn handle_disk_map_without_free_space() {
+        assert_eq!(
+            process_data("\n\n").unwrap_err().to_string(),
+            "input is missing a disk map"
+        );
+
+        for (disk_map, checksum) in [
+            ("1", 0),
+            ("12", 0),
+            ("302", 7),
+            ("13", 0),
+            ("131", 1),
+            ("10101", 5),
+        ] {
+            let mut unpacked_information = unpack_information(&process_data(disk_map).unwrap());
+            fragment_unpacked_information(&mut unpacked_information);
+            assert_eq!(calculate_checksum(&unpacked_information), checksum);
+        }
+    }
+
     #[test]
     fn solve_with_test_data() {
-        let dense_format_disk_map = process_data("./test_input/09.txt");
+        let file_content = std::fs::read_to_string("./test_input/09.txt").unwrap();
+        let dense_format_disk_map = process_data(&file_content).unwrap();
         let mut unpacked_information = unpack_information(&dense_format_disk_map);
         fragment_unpacked_information(&mut unpacked_information);
         let result = calculate_checksum(&unpacked_information);
@@ -1,10 +1,18 @@
-fn process_data(file_path: &str) -> Vec<u32> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
+use crate::parsing::{parse_digits, ParseError};
+use crate::solution::{Answer, Solution};
 
-    file_content
-        .chars()
-        .map(|c| c.to_digit(10).expect("Should be a digit between 0 and 9"))
-        .collect()
+fn process_data(file_content: &str) -> Result<Vec<u32>, ParseError> {
+    let mut disk_map: Vec<u32> = Vec::new();
+
+    for (line_index, line) in file_content.lines().enumerate() {
+        disk_map.extend(parse_digits(line_index, line)?);
+    }
+
+    if disk_map.is_empty() {
+        return Err(ParseError::missing_element("a disk map"));
+    }
+
+    Ok(disk_map)
 }
 
 fn unpack_information(disk_map: &[u32]) -> [Vec<(u32, u32)>; 2] {
@@ -60,12 +68,26 @@ fn calculate_checksum(files: &[(u32, u32)]) -> u64 {
         .sum()
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let dense_format_map: Vec<u32> = process_data("./input/09.txt");
-    let [mut files, mut free_space]: [Vec<(u32, u32)>; 2] = unpack_information(&dense_format_map);
-    move_whole_files(&mut files, &mut free_space);
-    let result: u64 = calculate_checksum(&files);
-    Ok(format!("Day 9 Disk Fragmenter (Part 2): {}.", result))
+pub(crate) struct Part2;
+
+impl Solution for Part2 {
+    type Parsed = Vec<u32>;
+
+    const DAY: u8 = 9;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, dense_format_map: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let [mut files, mut free_space]: [Vec<(u32, u32)>; 2] =
+            unpack_information(dense_format_map);
+        move_whole_files(&mut files, &mut free_space);
+        let result: u64 = calculate_checksum(&files);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -74,7 +96,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let dense_format_disk_map = process_data("./test_input/09.txt");
+        let file_content = std::fs::read_to_string("./test_input/09.txt").unwrap();
+        let dense_format_disk_map = process_data(&file_content).unwrap();
         let [mut files, mut free_space] = unpack_information(&dense_format_disk_map);
         move_whole_files(&mut files, &mut free_space);
         let result = calculate_checksum(&files);
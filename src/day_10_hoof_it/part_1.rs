@@ -1,62 +1,40 @@
-fn process_data(file_path: &str) -> Vec<Vec<u8>> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
-
-    file_content
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).expect("Should be a digit between 0 and 9") as u8)
-                .collect()
-        })
-        .collect()
+use crate::grid::{Grid, Point};
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
+
+fn process_data(file_content: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(
+        file_content,
+        |char| char.to_digit(10).map(|height| height as u8),
+        "a digit between 0 and 9",
+    )
 }
 
-fn get_starting_positions(topographic_map: &[Vec<u8>]) -> Vec<[usize; 2]> {
+fn get_starting_positions(topographic_map: &Grid<u8>) -> Vec<Point> {
     topographic_map
         .iter()
-        .enumerate()
-        .flat_map(|(y, row)| {
-            row.iter().enumerate().filter_map(
-                move |(x, &cell)| {
-                    if cell == 0 {
-                        Some([x, y])
-                    } else {
-                        None
-                    }
-                },
-            )
-        })
+        .filter(|&(_, &cell)| cell == 0)
+        .map(|(point, _)| point)
         .collect()
 }
 
-fn get_valid_neighbours(map: &[Vec<u8>], [x, y]: [usize; 2], target: u8) -> Vec<[usize; 2]> {
-    [
-        [x + 1, y],
-        [x, y + 1],
-        [x.wrapping_sub(1), y],
-        [x, y.wrapping_sub(1)],
-    ]
-    .into_iter()
-    .filter_map(|[nx, ny]| {
-        map.get(ny)
-            .and_then(|row| row.get(nx))
-            .filter(|&&c| c == target)
-            .map(|_| [nx, ny])
-    })
-    .collect()
+fn get_valid_neighbours(map: &Grid<u8>, point: Point, target: u8) -> Vec<Point> {
+    map.neighbours_4(point)
+        .filter(|&neighbour| map[neighbour] == target)
+        .collect()
 }
 
-fn calculate_trailhead_scores(topographic_map: &[Vec<u8>]) -> usize {
+fn calculate_trailhead_scores(topographic_map: &Grid<u8>) -> usize {
     let mut trailhead_scores: usize = 0;
-    let starting_positions: Vec<[usize; 2]> = get_starting_positions(topographic_map);
+    let starting_positions: Vec<Point> = get_starting_positions(topographic_map);
 
     for starting_position in &starting_positions {
-        let mut valid_cells: Vec<[usize; 2]> = Vec::from([*starting_position]);
+        let mut valid_cells: Vec<Point> = Vec::from([*starting_position]);
 
         for target in 1_u8..=9 {
-            let mut next_valid_cells: Vec<[usize; 2]> = valid_cells
+            let mut next_valid_cells: Vec<Point> = valid_cells
                 .into_iter()
-                .flat_map(|[x, y]| get_valid_neighbours(topographic_map, [x, y], target))
+                .flat_map(|point| get_valid_neighbours(topographic_map, point, target))
                 .collect();
 
             next_valid_cells.sort_unstable();
@@ -70,10 +48,23 @@ fn calculate_trailhead_scores(topographic_map: &[Vec<u8>]) -> usize {
     trailhead_scores
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let topographic_map: Vec<Vec<u8>> = process_data("./input/10.txt");
-    let result: usize = calculate_trailhead_scores(&topographic_map);
-    Ok(format!("Day 10 Hoof It (Part 1): {}.", result))
+pub(crate) struct Part1;
+
+impl Solution for Part1 {
+    type Parsed = Grid<u8>;
+
+    const DAY: u8 = 10;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Hoof It";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, topographic_map: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: usize = calculate_trailhead_scores(topographic_map);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -82,7 +73,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let topographic_map = process_data("./test_input/10.txt");
+        let file_content = std::fs::read_to_string("./test_input/10.txt").unwrap();
+        let topographic_map = process_data(&file_content).unwrap();
         let result = calculate_trailhead_scores(&topographic_map);
         assert_eq!(result, 36);
     }
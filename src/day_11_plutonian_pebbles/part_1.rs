@@ -1,9 +1,14 @@
-fn process_data(file_path: &str) -> Vec<u64> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
+use crate::parsing::{parse_value, ParseError};
+use crate::solution::{Answer, Solution};
 
+fn process_data(file_content: &str) -> Result<Vec<u64>, ParseError> {
     file_content
-        .split_whitespace()
-        .map(|num| num.parse().expect("Should be a valid u64 number"))
+        .lines()
+        .enumerate()
+        .flat_map(|(line_index, line)| {
+            line.split_whitespace()
+                .map(move |num| parse_value(line_index, line, num, "a valid u64 number"))
+        })
         .collect()
 }
 
@@ -27,7 +32,9 @@ fn blink_n_times(stones: Vec<u64>, n: u32) -> usize {
 
             match stone_number {
                 0 => next_stones.push(1),
-                num if digit_count % 2 == 0 => next_stones.extend(split_in_half(num, digit_count)),
+                num if digit_count.is_multiple_of(2) => {
+                    next_stones.extend(split_in_half(num, digit_count))
+                }
                 num => next_stones.push(num * 2024),
             }
         }
@@ -38,10 +45,23 @@ fn blink_n_times(stones: Vec<u64>, n: u32) -> usize {
     current_stones.len()
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let stones: Vec<u64> = process_data("./input/11.txt");
-    let result: usize = blink_n_times(stones, 25);
-    Ok(format!("Day 11 Plutonian Pebbles (Part 1): {}.", result))
+pub(crate) struct Part1;
+
+impl Solution for Part1 {
+    type Parsed = Vec<u64>;
+
+    const DAY: u8 = 11;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Plutonian Pebbles";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, stones: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: usize = blink_n_times(stones.clone(), 25);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -50,7 +70,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let stones = process_data("./test_input/11.txt");
+        let file_content = std::fs::read_to_string("./test_input/11.txt").unwrap();
+        let stones = process_data(&file_content).unwrap();
         let result = blink_n_times(stones, 25);
         assert_eq!(result, 55312);
     }
@@ -1,15 +1,18 @@
+use crate::parsing::{parse_value, ParseError};
+use crate::solution::{Answer, Solution};
 use std::collections::HashMap;
 
-fn process_data(file_path: &str) -> HashMap<u64, u64> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
+fn process_data(file_content: &str) -> Result<HashMap<u64, u64>, ParseError> {
+    let mut stones: HashMap<u64, u64> = HashMap::new();
 
-    file_content
-        .split_whitespace()
-        .map(|num| num.parse::<u64>().expect("Should be a valid u64 number"))
-        .fold(HashMap::new(), |mut acc, num| {
-            *acc.entry(num).or_default() += 1;
-            acc
-        })
+    for (line_index, line) in file_content.lines().enumerate() {
+        for num in line.split_whitespace() {
+            let num: u64 = parse_value(line_index, line, num, "a valid u64 number")?;
+            *stones.entry(num).or_default() += 1;
+        }
+    }
+
+    Ok(stones)
 }
 
 fn digit_count(number: u64) -> u32 {
@@ -32,7 +35,7 @@ fn blink_n_times(stones: HashMap<u64, u64>, n: u32) -> u64 {
 
             match stone_number {
                 0 => *next_stones.entry(1).or_default() += occurrence_count,
-                num if digit_count % 2 == 0 => split_in_half(num, digit_count)
+                num if digit_count.is_multiple_of(2) => split_in_half(num, digit_count)
                     .iter()
                     .for_each(|&half| *next_stones.entry(half).or_default() += occurrence_count),
                 num => *next_stones.entry(num * 2024).or_default() += occurrence_count,
@@ -45,10 +48,23 @@ fn blink_n_times(stones: HashMap<u64, u64>, n: u32) -> u64 {
     current_stones.values().sum()
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let stones: HashMap<u64, u64> = process_data("./input/11.txt");
-    let result: u64 = blink_n_times(stones, 75);
-    Ok(format!("Day 11 Plutonian Pebbles (Part 2): {}.", result))
+pub(crate) struct Part2;
+
+impl Solution for Part2 {
+    type Parsed = HashMap<u64, u64>;
+
+    const DAY: u8 = 11;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Plutonian Pebbles";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, stones: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: u64 = blink_n_times(stones.clone(), 75);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -57,7 +73,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let stones = process_data("./test_input/11.txt");
+        let file_content = std::fs::read_to_string("./test_input/11.txt").unwrap();
+        let stones = process_data(&file_content).unwrap();
         let result = blink_n_times(stones, 25);
         assert_eq!(result, 55312);
     }
@@ -1,63 +1,40 @@
+use crate::direction::Direction8;
+use crate::grid::{Grid, Point};
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
 use std::collections::HashMap;
 
-fn process_data(file_path: &str) -> Vec<Vec<char>> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
-
-    file_content
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect()
+fn process_data(file_content: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(
+        file_content,
+        |char| char.is_ascii_uppercase().then_some(char),
+        "an uppercase letter",
+    )
 }
 
-fn group_by_same_type(garden_plots: &[Vec<char>]) -> Vec<Vec<(u32, u32)>> {
-    let mut same_type_plots: HashMap<char, Vec<(u32, u32)>> = HashMap::new();
+fn group_by_same_type(garden_plots: &Grid<char>) -> Vec<Vec<Point>> {
+    let mut same_type_plots: HashMap<char, Vec<Point>> = HashMap::new();
 
-    for (y, row) in garden_plots.iter().enumerate() {
-        for (x, &char) in row.iter().enumerate() {
-            same_type_plots
-                .entry(char)
-                .or_default()
-                .push((x as u32, y as u32));
-        }
+    for (point, &char) in garden_plots.iter() {
+        same_type_plots.entry(char).or_default().push(point);
     }
 
     same_type_plots.into_values().collect()
 }
 
-fn get_cardinal_neighbours((x, y): (u32, u32)) -> [(u32, u32); 4] {
-    [
-        (x + 1, y),
-        (x, y + 1),
-        (x.wrapping_sub(1), y),
-        (x, y.wrapping_sub(1)),
-    ]
-}
+fn split_into_separate_plots(same_type_plots: &mut Vec<Point>) -> Vec<Vec<Point>> {
+    let mut separate_plots: Vec<Vec<Point>> = Vec::new();
 
-fn get_ordinal_neighbours((x, y): (u32, u32)) -> [(u32, u32); 4] {
-    [
-        (x + 1, y.wrapping_sub(1)),
-        (x + 1, y + 1),
-        (x.wrapping_sub(1), y + 1),
-        (x.wrapping_sub(1), y.wrapping_sub(1)),
-    ]
-}
+    while let Some(point) = same_type_plots.pop() {
+        let mut new_plot_group: Vec<Point> = Vec::from([point]);
+        let mut stack: Vec<Point> = Vec::from([point]);
 
-fn split_into_separate_plots(same_type_plots: &mut Vec<(u32, u32)>) -> Vec<Vec<(u32, u32)>> {
-    let mut separate_plots: Vec<Vec<(u32, u32)>> = Vec::new();
-
-    while let Some((x, y)) = same_type_plots.pop() {
-        let mut new_plot_group: Vec<(u32, u32)> = Vec::from([(x, y)]);
-        let mut stack: Vec<(u32, u32)> = Vec::from([(x, y)]);
-
-        while let Some((x, y)) = stack.pop() {
-            for (nx, ny) in get_cardinal_neighbours((x, y)) {
-                if let Some(pos) = same_type_plots
-                    .iter()
-                    .position(|&(px, py)| (px, py) == (nx, ny))
-                {
+        while let Some(point) = stack.pop() {
+            for neighbour in point.neighbours_4() {
+                if let Some(pos) = same_type_plots.iter().position(|&p| p == neighbour) {
                     same_type_plots.swap_remove(pos);
-                    stack.push((nx, ny));
-                    new_plot_group.push((nx, ny));
+                    stack.push(neighbour);
+                    new_plot_group.push(neighbour);
                 }
             }
         }
@@ -68,17 +45,27 @@ fn split_into_separate_plots(same_type_plots: &mut Vec<(u32, u32)>) -> Vec<Vec<(
     separate_plots
 }
 
-fn count_corners(plot: &[(u32, u32)]) -> u32 {
+fn count_corners(garden_plots: &Grid<char>, plot: &[Point]) -> u32 {
     let mut corner_count = 0;
 
-    for &(x, y) in plot {
-        let [e, s, w, n] = get_cardinal_neighbours((x, y));
-        let [ne, se, sw, nw] = get_ordinal_neighbours((x, y));
-        let corners = [(n, e, ne), (s, e, se), (s, w, sw), (n, w, nw)];
-
-        for (n1, n2, n3) in corners {
-            if plot.contains(&n1) && plot.contains(&n2) && !plot.contains(&n3)
-                || !plot.contains(&n1) && !plot.contains(&n2)
+    for &point in plot {
+        let same_plant: Vec<Point> = garden_plots
+            .neighbours_8(point)
+            .filter(|&neighbour| garden_plots[neighbour] == garden_plots[point])
+            .collect();
+
+        for diagonal in [
+            Direction8::NorthEast,
+            Direction8::SouthEast,
+            Direction8::SouthWest,
+            Direction8::NorthWest,
+        ] {
+            let [n1, n2, n3]: [Point; 3] =
+                [diagonal.rotate_left(), diagonal.rotate_right(), diagonal]
+                    .map(|direction| point + direction.delta());
+
+            if same_plant.contains(&n1) && same_plant.contains(&n2) && !same_plant.contains(&n3)
+                || !same_plant.contains(&n1) && !same_plant.contains(&n2)
             {
                 corner_count += 1;
             }
@@ -88,24 +75,37 @@ fn count_corners(plot: &[(u32, u32)]) -> u32 {
     corner_count
 }
 
-fn calculate_price_of_fencing(garden_plots: &[Vec<char>]) -> u32 {
-    let mut same_type_plots: Vec<Vec<(u32, u32)>> = group_by_same_type(garden_plots);
+fn calculate_price_of_fencing(garden_plots: &Grid<char>) -> u32 {
+    let mut same_type_plots: Vec<Vec<Point>> = group_by_same_type(garden_plots);
 
-    let separate_plots: Vec<Vec<(u32, u32)>> = same_type_plots
+    let separate_plots: Vec<Vec<Point>> = same_type_plots
         .iter_mut()
         .flat_map(split_into_separate_plots)
         .collect();
 
     separate_plots
         .iter()
-        .map(|plot| plot.len() as u32 * count_corners(plot))
+        .map(|plot| plot.len() as u32 * count_corners(garden_plots, plot))
         .sum()
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let garden_plots: Vec<Vec<char>> = process_data("./input/12.txt");
-    let result: u32 = calculate_price_of_fencing(&garden_plots);
-    Ok(format!("Day 12 Garden Groups (Part 2): {}.", result))
+pub(crate) struct Part2;
+
+impl Solution for Part2 {
+    type Parsed = Grid<char>;
+
+    const DAY: u8 = 12;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Garden Groups";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, garden_plots: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: u32 = calculate_price_of_fencing(garden_plots);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -114,7 +114,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let garden_plots = process_data("./test_input/12.txt");
+        let file_content = std::fs::read_to_string("./test_input/12.txt").unwrap();
+        let garden_plots = process_data(&file_content).unwrap();
         let result = calculate_price_of_fencing(&garden_plots);
         assert_eq!(result, 1206);
     }
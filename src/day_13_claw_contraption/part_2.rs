@@ -1,31 +1,48 @@
-fn process_data(file_path: &str) -> Vec<[[u64; 2]; 3]> {
-    let parse_line = |line: &&str| -> [u64; 2] {
-        line.replace("=", "+")
-            .split_once('+')
-            .and_then(|(_, numbers)| numbers.split_once(", Y+"))
-            .map(|(num_1, num_2)| {
-                [
-                    num_1.parse().expect("Should be a valid u64 number"),
-                    num_2.parse().expect("Should be a valid u64 number"),
-                ]
-            })
-            .expect("Line should have the correct format")
+use crate::parsing::{parse_value, split_at_delimiter, ParseError};
+use crate::solution::{Answer, Solution};
+
+fn parse_coordinates(line_index: usize, line: &str) -> Result<[u64; 2], ParseError> {
+    let (_, coordinates) =
+        split_at_delimiter(line_index, line, line, ": ", "a label followed by ': '")?;
+    let (x, y) = split_at_delimiter(
+        line_index,
+        line,
+        coordinates,
+        ", ",
+        "X and Y separated by ', '",
+    )?;
+
+    let parse_coordinate = |text: &str, axis: char| -> Result<u64, ParseError> {
+        let number: &str = text
+            .strip_prefix(axis)
+            .and_then(|text| text.strip_prefix('+').or_else(|| text.strip_prefix('=')))
+            .ok_or_else(|| {
+                ParseError::unexpected_format(line_index, line, text, "X+N, Y+N, X=N or Y=N")
+            })?;
+        parse_value(line_index, line, number, "a valid u64 number")
     };
 
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
+    Ok([parse_coordinate(x, 'X')?, parse_coordinate(y, 'Y')?])
+}
 
-    file_content
+fn process_data(file_content: &str) -> Result<Vec<[[u64; 2]; 3]>, ParseError> {
+    let lines: Vec<(usize, &str)> = file_content
         .lines()
-        .filter(|line| !line.is_empty())
-        .collect::<Vec<_>>()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .collect();
+
+    lines
         .chunks(3)
-        .map(|chunk| {
-            chunk
-                .iter()
-                .map(parse_line) // Directly map `parse_line`, no need for extra closure
-                .collect::<Vec<[u64; 2]>>()
-                .try_into()
-                .expect("Each chunk should contain exactly 3 entries of [u64; 2]")
+        .map(|chunk| match chunk {
+            [button_a, button_b, prize] => Ok([
+                parse_coordinates(button_a.0, button_a.1)?,
+                parse_coordinates(button_b.0, button_b.1)?,
+                parse_coordinates(prize.0, prize.1)?,
+            ]),
+            _ => Err(ParseError::missing_element(
+                "two buttons and a prize for every claw machine",
+            )),
         })
         .collect()
 }
@@ -64,10 +81,23 @@ fn calculate_number_of_used_tokens(data: &[[[u64; 2]; 3]]) -> u64 {
     tokens_used
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let data: Vec<[[u64; 2]; 3]> = process_data("./input/13.txt");
-    let result: u64 = calculate_number_of_used_tokens(&data);
-    Ok(format!("Day 13 Claw Contraption (Part 2): {}.", result))
+pub(crate) struct Part2;
+
+impl Solution for Part2 {
+    type Parsed = Vec<[[u64; 2]; 3]>;
+
+    const DAY: u8 = 13;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Claw Contraption";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, data: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: u64 = calculate_number_of_used_tokens(data);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -76,7 +106,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let button_behaviors_and_prize_locations = process_data("./test_input/13.txt");
+        let file_content = std::fs::read_to_string("./test_input/13.txt").unwrap();
+        let button_behaviors_and_prize_locations = process_data(&file_content).unwrap();
         let result = calculate_number_of_used_tokens(&button_behaviors_and_prize_locations);
         assert_eq!(result, 875318608908);
     }
@@ -0,0 +1,217 @@
+use crate::cli::DetectorKind;
+use crate::day_14_restroom_redoubt::part_2::nth_guard_positions;
+use crate::day_14_restroom_redoubt::room::{calculate_greatest_common_divisor, RoomSize};
+use std::collections::{HashMap, HashSet};
+
+const REGIONS_PER_SIDE: i32 = 4;
+
+pub(crate) trait PatternDetector {
+    fn name(&self) -> &'static str;
+    fn score(&self, guard_positions: &HashSet<(i32, i32)>, room: RoomSize) -> f64;
+}
+
+pub(crate) struct ClosenessDetector;
+pub(crate) struct QuadrantEntropyDetector;
+pub(crate) struct LongestRunDetector;
+pub(crate) struct BoundingBoxDensityDetector;
+
+impl PatternDetector for ClosenessDetector {
+    fn name(&self) -> &'static str {
+        "closeness"
+    }
+
+    fn score(&self, guard_positions: &HashSet<(i32, i32)>, _room: RoomSize) -> f64 {
+        guard_positions
+            .iter()
+            .flat_map(|&(x, y)| [(x + 1, y), (x, y + 1), (x - 1, y), (x, y - 1)])
+            .filter(|neighbour| guard_positions.contains(neighbour))
+            .count() as f64
+    }
+}
+
+impl PatternDetector for QuadrantEntropyDetector {
+    fn name(&self) -> &'static str {
+        "entropy"
+    }
+
+    fn score(&self, guard_positions: &HashSet<(i32, i32)>, room: RoomSize) -> f64 {
+        let mut region_counts: HashMap<(i32, i32), usize> = HashMap::new();
+
+        for &(x, y) in guard_positions {
+            let region: (i32, i32) = (
+                x * REGIONS_PER_SIDE / room.width,
+                y * REGIONS_PER_SIDE / room.height,
+            );
+            *region_counts.entry(region).or_default() += 1;
+        }
+
+        let total: f64 = guard_positions.len() as f64;
+        let entropy: f64 = region_counts
+            .values()
+            .map(|&count| count as f64 / total)
+            .map(|probability| -probability * probability.log2())
+            .sum();
+
+        ((REGIONS_PER_SIDE * REGIONS_PER_SIDE) as f64).log2() - entropy
+    }
+}
+
+impl PatternDetector for LongestRunDetector {
+    fn name(&self) -> &'static str {
+        "run"
+    }
+
+    fn score(&self, guard_positions: &HashSet<(i32, i32)>, _room: RoomSize) -> f64 {
+        guard_positions
+            .iter()
+            .filter(|&&(x, y)| !guard_positions.contains(&(x - 1, y)))
+            .map(|&(x, y)| {
+                (x..)
+                    .take_while(|&next| guard_positions.contains(&(next, y)))
+                    .count()
+            })
+            .max()
+            .unwrap_or(0) as f64
+    }
+}
+
+impl PatternDetector for BoundingBoxDensityDetector {
+    fn name(&self) -> &'static str {
+        "density"
+    }
+
+    fn score(&self, guard_positions: &HashSet<(i32, i32)>, _room: RoomSize) -> f64 {
+        let mut xs: Vec<i32> = guard_positions.iter().map(|&(x, _)| x).collect();
+        let mut ys: Vec<i32> = guard_positions.iter().map(|&(_, y)| y).collect();
+        xs.sort_unstable();
+        ys.sort_unstable();
+
+        let Some([x_min, x_max, y_min, y_max]) = find_interquartile_bounds(&xs, &ys) else {
+            return 0.0;
+        };
+
+        let inside: usize = guard_positions
+            .iter()
+            .filter(|&&(x, y)| (x_min..=x_max).contains(&x) && (y_min..=y_max).contains(&y))
+            .count();
+
+        inside as f64 / ((x_max - x_min + 1) * (y_max - y_min + 1)) as f64
+    }
+}
+
+fn find_interquartile_bounds(xs: &[i32], ys: &[i32]) -> Option<[i32; 4]> {
+    let quartile = |values: &[i32], numerator: usize| -> Option<i32> {
+        values.get(values.len() * numerator / 4).copied()
+    };
+
+    Some([
+        quartile(xs, 1)?,
+        quartile(xs, 3)?,
+        quartile(ys, 1)?,
+        quartile(ys, 3)?,
+    ])
+}
+
+pub(crate) fn build_detector(kind: DetectorKind) -> Box<dyn PatternDetector> {
+    match kind {
+        DetectorKind::Closeness => Box::new(ClosenessDetector),
+        DetectorKind::Entropy => Box::new(QuadrantEntropyDetector),
+        DetectorKind::Run => Box::new(LongestRunDetector),
+        DetectorKind::Density => Box::new(BoundingBoxDensityDetector),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct Candidate {
+    pub(crate) step: i32,
+    pub(crate) score: f64,
+}
+
+pub(crate) fn find_top_candidates(
+    guard_positions_and_velocities: &[[i32; 4]],
+    room: RoomSize,
+    detector: &dyn PatternDetector,
+    k: usize,
+) -> Vec<Candidate> {
+    let period: i32 =
+        room.width / calculate_greatest_common_divisor(room.width, room.height) * room.height;
+    let mut candidates: Vec<Candidate> = (0..period)
+        .map(|n| {
+            let guard_positions: HashSet<(i32, i32)> =
+                nth_guard_positions(guard_positions_and_velocities, n, room.height, room.width);
+            Candidate {
+                step: n,
+                score: detector.score(&guard_positions, room),
+            }
+        })
+        .collect();
+
+    candidates.sort_by(|first, second| {
+        second
+            .score
+            .total_cmp(&first.score)
+            .then(first.step.cmp(&second.step))
+    });
+    candidates.truncate(k);
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_14_restroom_redoubt::detector::*;
+
+    #[test]
+    fn rank_candidate_steps_with_each_detector() {
+        let room = RoomSize {
+            width: 11,
+            height: 7,
+        };
+        let velocities: [[i32; 2]; 9] = [
+            [1, 2],
+            [-3, 1],
+            [4, -2],
+            [2, 3],
+            [-1, -1],
+            [5, 2],
+            [-4, 3],
+            [3, -3],
+            [-2, 1],
+        ];
+        let guard_positions_and_velocities: Vec<[i32; 4]> = velocities
+            .iter()
+            .enumerate()
+            .map(|(index, &[vx, vy])| {
+                let [x, y]: [i32; 2] = [4 + index as i32 % 3, 2 + index as i32 / 3];
+                [
+                    (x - vx * 13).rem_euclid(room.width),
+                    (y - vy * 13).rem_euclid(room.height),
+                    vx,
+                    vy,
+                ]
+            })
+            .collect();
+
+        for kind in [
+            DetectorKind::Closeness,
+            DetectorKind::Entropy,
+            DetectorKind::Run,
+            DetectorKind::Density,
+        ] {
+            let detector = build_detector(kind);
+            let candidates =
+                find_top_candidates(&guard_positions_and_velocities, room, detector.as_ref(), 3);
+            assert_eq!(candidates.len(), 3);
+            assert_eq!(candidates[0].step, 13, "{}", detector.name());
+        }
+
+        let candidates =
+            find_top_candidates(&guard_positions_and_velocities, room, &ClosenessDetector, 1);
+        assert_eq!(
+            candidates,
+            vec![Candidate {
+                step: 13,
+                score: 24.0
+            }]
+        );
+    }
+}
@@ -1,2 +1,87 @@
+use crate::cli::{DetectorKind, PuzzleOptions, TreeOptions};
+use crate::solution::Registry;
+
+pub(crate) mod detector;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod room;
+
+pub(crate) fn register(registry: &mut Registry, options: &PuzzleOptions) {
+    registry.register(part_1::Part1 {
+        room_size: options.room_size,
+    });
+    registry.register(part_2::Part2 {
+        room_size: options.room_size,
+    });
+}
+
+pub(crate) fn run_tree_command(
+    options: &TreeOptions,
+    puzzle_options: &PuzzleOptions,
+    file_content: &str,
+) -> bool {
+    let guard_positions_and_velocities: Vec<[i32; 4]> = match part_2::process_data(file_content) {
+        Ok(guard_positions_and_velocities) => guard_positions_and_velocities,
+        Err(error) => {
+            eprintln!("{}", error);
+            return false;
+        }
+    };
+
+    let room: room::RoomSize =
+        match room::find_room_size(&guard_positions_and_velocities, puzzle_options.room_size) {
+            Ok(room) => room,
+            Err(error) => {
+                eprintln!("{}", error);
+                return false;
+            }
+        };
+
+    let kinds: &[DetectorKind] = match options.detectors.is_empty() {
+        true => &[
+            DetectorKind::Closeness,
+            DetectorKind::Entropy,
+            DetectorKind::Run,
+            DetectorKind::Density,
+        ],
+        false => &options.detectors,
+    };
+
+    println!(
+        "Room {}, {} guards",
+        room,
+        guard_positions_and_velocities.len()
+    );
+
+    match part_2::find_tree(&guard_positions_and_velocities, room) {
+        Ok(detection) => println!(
+            "Variance search: second {} (confidence {:.2})",
+            detection.step, detection.confidence
+        ),
+        Err(error) => println!("Variance search: {}", error),
+    }
+
+    for &kind in kinds {
+        let detector: Box<dyn detector::PatternDetector> = detector::build_detector(kind);
+        println!("\n{} detector:", detector.name());
+
+        for (rank, candidate) in detector::find_top_candidates(
+            &guard_positions_and_velocities,
+            room,
+            detector.as_ref(),
+            options.top,
+        )
+        .iter()
+        .enumerate()
+        {
+            println!(
+                "{:>3}. second {:>6}, score {:.3}",
+                rank + 1,
+                candidate.step,
+                candidate.score
+            );
+        }
+    }
+
+    true
+}
@@ -1,17 +1,28 @@
-fn process_data(file_path: &str) -> Vec<[i32; 4]> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
+use crate::day_14_restroom_redoubt::room::{find_room_size, RoomSize};
+use crate::parsing::{parse_value, split_at_delimiter, ParseError};
+use crate::solution::{Answer, Solution};
 
+fn process_data(file_content: &str) -> Result<Vec<[i32; 4]>, ParseError> {
     file_content
         .lines()
-        .map(|line| {
-            line.replace("p=", "")
-                .replace("v=", "")
-                .replace(" ", ",")
-                .split(',')
-                .map(|num| num.parse::<i32>().expect("Should be a valid i32 number"))
-                .collect::<Vec<i32>>()
-                .try_into()
-                .expect("Should produce [i32; 4]")
+        .enumerate()
+        .map(|(line_index, line)| {
+            let parse_pair = |text: &str, prefix: &str| -> Result<[i32; 2], ParseError> {
+                let pair: &str = text.strip_prefix(prefix).ok_or_else(|| {
+                    ParseError::unexpected_format(line_index, line, text, "p=X,Y v=X,Y")
+                })?;
+                let (x, y) = split_at_delimiter(line_index, line, pair, ",", "X,Y")?;
+                Ok([
+                    parse_value(line_index, line, x, "a valid i32 number")?,
+                    parse_value(line_index, line, y, "a valid i32 number")?,
+                ])
+            };
+
+            let (position, velocity) =
+                split_at_delimiter(line_index, line, line, " ", "p=X,Y v=X,Y")?;
+            let [px, py] = parse_pair(position, "p=")?;
+            let [vx, vy] = parse_pair(velocity, "v=")?;
+            Ok([px, py, vx, vy])
         })
         .collect()
 }
@@ -51,18 +62,36 @@ fn simulate_guard_positions_after_n_seconds(
         .collect()
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let guard_positions_and_velocities: Vec<[i32; 4]> = process_data("./input/14.txt");
-    let height: i32 = 103;
-    let width: i32 = 101;
-    let guard_positions: Vec<[i32; 2]> = simulate_guard_positions_after_n_seconds(
-        &guard_positions_and_velocities,
-        100,
-        height,
-        width,
-    );
-    let result: u32 = calculate_safety_factor(&guard_positions, height, width);
-    Ok(format!("Day 14 Restroom Redoubt (Part 1): {}.", result))
+pub(crate) struct Part1 {
+    pub(crate) room_size: Option<RoomSize>,
+}
+
+impl Solution for Part1 {
+    type Parsed = Vec<[i32; 4]>;
+
+    const DAY: u8 = 14;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Restroom Redoubt";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(
+        &self,
+        guard_positions_and_velocities: &Self::Parsed,
+    ) -> Result<Answer, Box<dyn std::error::Error>> {
+        let room: RoomSize = find_room_size(guard_positions_and_velocities, self.room_size)?;
+        let (height, width) = (room.height, room.width);
+        let guard_positions: Vec<[i32; 2]> = simulate_guard_positions_after_n_seconds(
+            guard_positions_and_velocities,
+            100,
+            height,
+            width,
+        );
+        let result: u32 = calculate_safety_factor(&guard_positions, height, width);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -71,7 +100,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let guard_positions_and_velocities = process_data("./test_input/14.txt");
+        let file_content = std::fs::read_to_string("./test_input/14.txt").unwrap();
+        let guard_positions_and_velocities = process_data(&file_content).unwrap();
         let height = 11;
         let width = 7;
         let guard_positions = simulate_guard_positions_after_n_seconds(
@@ -1,36 +1,67 @@
+use crate::day_14_restroom_redoubt::room::{
+    calculate_greatest_common_divisor, find_room_size, RoomSize,
+};
+use crate::grid::{Grid, Point};
+use crate::parsing::{parse_value, split_at_delimiter, ParseError};
+use crate::render::{Renderer, Style, Tile};
+use crate::solution::{Answer, Solution};
 use std::collections::HashSet;
+use std::fmt;
+use std::io;
 
-fn process_data(file_path: &str) -> Vec<[i32; 4]> {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
+#[derive(Debug, PartialEq)]
+pub(crate) struct TreeDetection {
+    pub(crate) step: i32,
+    pub(crate) confidence: f64,
+}
 
-    file_content
-        .lines()
-        .map(|line| {
-            line.replace("p=", "")
-                .replace("v=", "")
-                .replace(" ", ",")
-                .split(',')
-                .map(|num| num.parse::<i32>().expect("Should be a valid i32 number"))
-                .collect::<Vec<i32>>()
-                .try_into()
-                .expect("Should produce [i32; 4]")
-        })
-        .collect()
+#[derive(Debug, PartialEq)]
+pub(crate) enum TreeError {
+    NoGuards,
+    PeriodsNotCoprime { room: RoomSize },
 }
 
-fn get_neighbours((x, y): (i32, i32)) -> [(i32, i32); 4] {
-    [(x + 1, y), (x, y + 1), (x - 1, y), (x, y - 1)]
+impl fmt::Display for TreeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TreeError::NoGuards => write!(f, "there are no guards to form a tree"),
+            TreeError::PeriodsNotCoprime { room } => write!(
+                f,
+                "the {} room has no unique tree step because its sides share a common divisor",
+                room
+            ),
+        }
+    }
 }
 
-fn calculate_closeness_factor(guard_positions: &HashSet<(i32, i32)>) -> usize {
-    guard_positions
-        .iter()
-        .flat_map(|&(x, y)| get_neighbours((x, y)))
-        .filter(|&(nx, ny)| guard_positions.contains(&(nx, ny)))
-        .count()
+impl std::error::Error for TreeError {}
+
+pub(crate) fn process_data(file_content: &str) -> Result<Vec<[i32; 4]>, ParseError> {
+    file_content
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| {
+            let parse_pair = |text: &str, prefix: &str| -> Result<[i32; 2], ParseError> {
+                let pair: &str = text.strip_prefix(prefix).ok_or_else(|| {
+                    ParseError::unexpected_format(line_index, line, text, "p=X,Y v=X,Y")
+                })?;
+                let (x, y) = split_at_delimiter(line_index, line, pair, ",", "X,Y")?;
+                Ok([
+                    parse_value(line_index, line, x, "a valid i32 number")?,
+                    parse_value(line_index, line, y, "a valid i32 number")?,
+                ])
+            };
+
+            let (position, velocity) =
+                split_at_delimiter(line_index, line, line, " ", "p=X,Y v=X,Y")?;
+            let [px, py] = parse_pair(position, "p=")?;
+            let [vx, vy] = parse_pair(velocity, "v=")?;
+            Ok([px, py, vx, vy])
+        })
+        .collect()
 }
 
-fn nth_guard_positions(
+pub(crate) fn nth_guard_positions(
     guard_positions_and_velocities: &[[i32; 4]],
     n: i32,
     height: i32,
@@ -47,42 +78,169 @@ fn nth_guard_positions(
         .collect()
 }
 
-fn print_tree(guard_positions_and_velocities: &[[i32; 4]], n: i32, height: i32, width: i32) {
-    let guard_positions: HashSet<(i32, i32)> =
-        nth_guard_positions(guard_positions_and_velocities, n, height, width);
-    let mut grid = [[' '; 101]; 103];
+fn build_frame(guard_positions: &HashSet<(i32, i32)>, height: i32, width: i32) -> Grid<Tile> {
+    let mut frame: Grid<Tile> = Grid::filled(
+        width as usize,
+        height as usize,
+        Tile::new(' ', Style::Plain),
+    );
+
+    for &(x, y) in guard_positions {
+        frame[Point::new(x, y)] = Tile::new('#', Style::Path);
+    }
+
+    frame
+}
+
+fn calculate_variance(values: &[i32]) -> f64 {
+    let mean: f64 = values.iter().map(|&value| value as f64).sum::<f64>() / values.len() as f64;
+
+    values
+        .iter()
+        .map(|&value| (value as f64 - mean).powi(2))
+        .sum::<f64>()
+        / values.len() as f64
+}
+
+fn find_most_clustered_step_on_axis(
+    guard_positions_and_velocities: &[[i32; 4]],
+    axis: usize,
+    period: i32,
+) -> (i32, f64) {
+    let variances: Vec<f64> = (0..period)
+        .map(|n| {
+            let values: Vec<i32> = guard_positions_and_velocities
+                .iter()
+                .map(|guard| (guard[axis] + guard[axis + 2] * n).rem_euclid(period))
+                .collect();
+            calculate_variance(&values)
+        })
+        .collect();
+
+    let (step, min_variance): (usize, f64) = variances
+        .iter()
+        .copied()
+        .enumerate()
+        .min_by(|(_, first), (_, second)| first.total_cmp(second))
+        .expect("Room should have at least one step per axis");
+    let mean_variance: f64 = variances.iter().sum::<f64>() / variances.len() as f64;
 
-    for (x, y) in guard_positions {
-        grid[y as usize][x as usize] = '#';
+    match mean_variance > 0.0 {
+        true => (step as i32, 1.0 - min_variance / mean_variance),
+        false => (step as i32, 0.0),
     }
+}
 
-    for row in grid {
-        println!("{}", row.iter().collect::<String>());
+pub(crate) fn find_tree(
+    guard_positions_and_velocities: &[[i32; 4]],
+    room: RoomSize,
+) -> Result<TreeDetection, TreeError> {
+    if guard_positions_and_velocities.is_empty() {
+        return Err(TreeError::NoGuards);
     }
+
+    if calculate_greatest_common_divisor(room.width, room.height) != 1 {
+        return Err(TreeError::PeriodsNotCoprime { room });
+    }
+
+    let (step_x, confidence_x) =
+        find_most_clustered_step_on_axis(guard_positions_and_velocities, 0, room.width);
+    let (step_y, confidence_y) =
+        find_most_clustered_step_on_axis(guard_positions_and_velocities, 1, room.height);
+    let step: i32 = (0..room.height)
+        .map(|k| step_x + k * room.width)
+        .find(|n| n % room.height == step_y)
+        .expect("Coprime periods should have a common step");
+
+    Ok(TreeDetection {
+        step,
+        confidence: confidence_x.min(confidence_y),
+    })
 }
 
-fn find_tree(guard_positions_and_velocities: &[[i32; 4]], height: i32, width: i32) -> i32 {
-    let mut max_closeness_factor: usize = 0;
-    let mut tree_iter = 0;
+pub(crate) struct Part2 {
+    pub(crate) room_size: Option<RoomSize>,
+}
 
-    for n in 1..height * width {
-        let guard_positions: HashSet<(i32, i32)> =
-            nth_guard_positions(guard_positions_and_velocities, n, height, width);
+impl Solution for Part2 {
+    type Parsed = Vec<[i32; 4]>;
 
-        let closeness_factor = calculate_closeness_factor(&guard_positions);
+    const DAY: u8 = 14;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Restroom Redoubt";
 
-        if closeness_factor > max_closeness_factor {
-            max_closeness_factor = closeness_factor;
-            tree_iter = n;
-        }
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(
+        &self,
+        guard_positions_and_velocities: &Self::Parsed,
+    ) -> Result<Answer, Box<dyn std::error::Error>> {
+        let room: RoomSize = find_room_size(guard_positions_and_velocities, self.room_size)?;
+        let detection: TreeDetection = find_tree(guard_positions_and_velocities, room)?;
+        Ok(Answer::from(detection.step as u64))
     }
 
-    print_tree(guard_positions_and_velocities, tree_iter, height, width);
-    tree_iter
+    fn visualize(
+        &self,
+        guard_positions_and_velocities: &Self::Parsed,
+        renderer: &mut Renderer,
+    ) -> io::Result<()> {
+        let room: RoomSize = find_room_size(guard_positions_and_velocities, self.room_size)
+            .map_err(io::Error::other)?;
+        let detection: TreeDetection =
+            find_tree(guard_positions_and_velocities, room).map_err(io::Error::other)?;
+        let guard_positions: HashSet<(i32, i32)> = nth_guard_positions(
+            guard_positions_and_velocities,
+            detection.step,
+            room.height,
+            room.width,
+        );
+        renderer.render(&build_frame(&guard_positions, room.height, room.width))
+    }
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let guard_positions_and_velocities: Vec<[i32; 4]> = process_data("./input/14.txt");
-    let result = find_tree(&guard_positions_and_velocities, 103, 101);
-    Ok(format!("Day 14 Restroom Redoubt (Part 2): {}.", result))
+#[cfg(test)]
+mod tests {
+    use crate::day_14_restroom_redoubt::part_2::*;
+
+    #[test]
+    fn find_tree_from_independent_periods() {
+        let room = RoomSize {
+            width: 11,
+            height: 7,
+        };
+        let tree: [[i32; 2]; 6] = [[2, 2], [3, 2], [4, 2], [3, 1], [3, 3], [3, 4]];
+        let mut guard_positions_and_velocities: Vec<[i32; 4]> = tree
+            .iter()
+            .zip([[1, 2], [-3, 1], [4, -2], [2, 3], [-1, -1], [5, 2]])
+            .map(|(&[x, y], [vx, vy])| {
+                [
+                    (x - vx * 40).rem_euclid(room.width),
+                    (y - vy * 40).rem_euclid(room.height),
+                    vx,
+                    vy,
+                ]
+            })
+            .collect();
+        let detection = find_tree(&guard_positions_and_velocities, room).unwrap();
+        assert_eq!(detection.step, 40);
+        assert!(detection.confidence > 0.4);
+
+        guard_positions_and_velocities.clear();
+        assert_eq!(
+            find_tree(&guard_positions_and_velocities, room),
+            Err(TreeError::NoGuards)
+        );
+
+        let room = RoomSize {
+            width: 10,
+            height: 4,
+        };
+        assert_eq!(
+            find_tree(&[[1, 1, 1, 1]], room).unwrap_err().to_string(),
+            "the 10x4 room has no unique tree step because its sides share a common divisor"
+        );
+    }
 }
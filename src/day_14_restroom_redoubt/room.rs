@@ -0,0 +1,111 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct RoomSize {
+    pub(crate) width: i32,
+    pub(crate) height: i32,
+}
+
+impl fmt::Display for RoomSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum RoomError {
+    GuardOutsideRoom { position: [i32; 2], room: RoomSize },
+}
+
+impl fmt::Display for RoomError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RoomError::GuardOutsideRoom {
+                position: [x, y],
+                room,
+            } => write!(f, "guard at {},{} is outside the {} room", x, y, room),
+        }
+    }
+}
+
+impl std::error::Error for RoomError {}
+
+pub(crate) fn parse_room_size(text: &str) -> Option<RoomSize> {
+    let (width, height) = text.split_once('x')?;
+
+    match (width.trim().parse(), height.trim().parse()) {
+        (Ok(width @ 1..), Ok(height @ 1..)) => Some(RoomSize { width, height }),
+        _ => None,
+    }
+}
+
+fn infer_room_size(guard_positions_and_velocities: &[[i32; 4]]) -> RoomSize {
+    let width: i32 = guard_positions_and_velocities
+        .iter()
+        .map(|&[px, ..]| px + 1)
+        .max()
+        .unwrap_or(1);
+    let height: i32 = guard_positions_and_velocities
+        .iter()
+        .map(|&[_, py, ..]| py + 1)
+        .max()
+        .unwrap_or(1);
+
+    RoomSize { width, height }
+}
+
+pub(crate) fn find_room_size(
+    guard_positions_and_velocities: &[[i32; 4]],
+    configured: Option<RoomSize>,
+) -> Result<RoomSize, RoomError> {
+    let room: RoomSize =
+        configured.unwrap_or_else(|| infer_room_size(guard_positions_and_velocities));
+
+    match guard_positions_and_velocities
+        .iter()
+        .find(|&&[px, py, ..]| !(0..room.width).contains(&px) || !(0..room.height).contains(&py))
+    {
+        Some(&[px, py, ..]) => Err(RoomError::GuardOutsideRoom {
+            position: [px, py],
+            room,
+        }),
+        None => Ok(room),
+    }
+}
+
+pub(crate) fn calculate_greatest_common_divisor(a: i32, b: i32) -> i32 {
+    match b {
+        0 => a,
+        _ => calculate_greatest_common_divisor(b, a % b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_14_restroom_redoubt::room::*;
+
+    #[test]
+    fn configure_or_infer_room_size() {
+        let guards = [[0, 4, 3, -3], [10, 3, -1, 2], [2, 6, 1, 1]];
+        let room = find_room_size(&guards, None);
+        assert_eq!(
+            room,
+            Ok(RoomSize {
+                width: 11,
+                height: 7
+            })
+        );
+
+        let room = find_room_size(&[[120, 5, 1, 1]], None);
+        assert_eq!(room.unwrap().to_string(), "121x6");
+
+        let room = find_room_size(&guards, parse_room_size("20x30"));
+        assert_eq!(room.unwrap().to_string(), "20x30");
+
+        assert_eq!(parse_room_size("20x0"), None);
+        assert_eq!(parse_room_size("20"), None);
+
+        let error = find_room_size(&guards, parse_room_size("5x5")).unwrap_err();
+        assert_eq!(error.to_string(), "guard at 10,3 is outside the 5x5 room");
+    }
+}
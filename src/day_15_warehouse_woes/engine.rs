@@ -0,0 +1,472 @@
+use crate::direction::Direction;
+use crate::grid::{Grid, Point};
+use crate::parsing::ParseError;
+use crate::render::{format_ansi_frame, Renderer, Style, Tile};
+use std::cmp::Reverse;
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, BufRead, Write};
+
+const MOVES_PER_LINE: usize = 1000;
+const ANIMATION_FRAMES: usize = 10;
+const HELP: &str = "Arrow keys or <^>v move the robot, u undoes the last move, q quits";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Object {
+    Wall,
+    Box(usize),
+    Nothing,
+    Robot,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Step {
+    direction: Direction,
+    moved: Vec<Point>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Warehouse {
+    map: Grid<Object>,
+    robot: Point,
+    history: Vec<Step>,
+}
+
+impl Warehouse {
+    pub(crate) fn new(map: Grid<Object>) -> Option<Self> {
+        let robot: Point = map.find(&Object::Robot)?;
+
+        Some(Warehouse {
+            map,
+            robot,
+            history: Vec::new(),
+        })
+    }
+
+    pub(crate) fn step_count(&self) -> usize {
+        self.history.len()
+    }
+
+    fn swap_objects(&mut self, point: Point, next: Point) {
+        let object: Object = self.map[point];
+        self.map[point] = self.map[next];
+        self.map[next] = object;
+    }
+
+    fn find_box_cells(&self, start: Point, id: usize) -> Vec<Point> {
+        let mut cells: Vec<Point> = vec![start];
+        let mut index: usize = 0;
+
+        while let Some(&cell) = cells.get(index) {
+            for neighbour in self.map.neighbours_4(cell) {
+                if self.map[neighbour] == Object::Box(id) && !cells.contains(&neighbour) {
+                    cells.push(neighbour);
+                }
+            }
+
+            index += 1;
+        }
+
+        cells
+    }
+
+    fn find_pushed_objects(&self, direction: Direction) -> Option<Vec<Point>> {
+        let mut queue: VecDeque<Point> = VecDeque::from([self.robot]);
+        let mut seen: Vec<Point> = Vec::new();
+        let delta: Point = direction.delta();
+
+        while let Some(point) = queue.pop_front() {
+            if !seen.contains(&point) {
+                seen.push(point);
+                let next: Point = point + delta;
+
+                match self.map.get(next)? {
+                    Object::Wall | Object::Robot => return None,
+                    &Object::Box(id) => queue.extend(self.find_box_cells(next, id)),
+                    Object::Nothing => continue,
+                }
+            }
+        }
+
+        seen.sort_by_key(|point| Reverse(point.x * delta.x + point.y * delta.y));
+        Some(seen)
+    }
+
+    pub(crate) fn step(&mut self, direction: Direction) -> bool {
+        let moved: Vec<Point> = self.find_pushed_objects(direction).unwrap_or_default();
+        let delta: Point = direction.delta();
+
+        for &point in &moved {
+            self.swap_objects(point, point + delta);
+        }
+
+        if !moved.is_empty() {
+            self.robot = self.robot + delta;
+        }
+
+        self.history.push(Step { direction, moved });
+        self.history
+            .last()
+            .is_some_and(|step| !step.moved.is_empty())
+    }
+
+    pub(crate) fn undo(&mut self) -> Option<Direction> {
+        let step: Step = self.history.pop()?;
+        let delta: Point = step.direction.delta();
+
+        for &point in step.moved.iter().rev() {
+            self.swap_objects(point, point + delta);
+        }
+
+        if !step.moved.is_empty() {
+            self.robot = self.robot - delta;
+        }
+
+        Some(step.direction)
+    }
+
+    pub(crate) fn run(&mut self, movements: &[Direction]) {
+        for &movement in movements {
+            self.step(movement);
+        }
+    }
+
+    pub(crate) fn sum_gps_coordinates(&self) -> usize {
+        let mut box_corners: HashMap<usize, Point> = HashMap::new();
+
+        for (point, object) in self.map.iter() {
+            if let &Object::Box(id) = object {
+                box_corners.entry(id).or_insert(point);
+            }
+        }
+
+        box_corners
+            .values()
+            .map(|point| 100 * point.y as usize + point.x as usize)
+            .sum()
+    }
+
+    pub(crate) fn recorded_moves(&self) -> String {
+        self.history
+            .iter()
+            .map(|step| step.direction.to_char())
+            .collect()
+    }
+
+    pub(crate) fn build_frame(&self) -> Grid<Tile> {
+        let mut frame: Grid<Tile> = Grid::filled(
+            self.map.width(),
+            self.map.height(),
+            Tile::new('.', Style::Plain),
+        );
+
+        for (point, &object) in self.map.iter() {
+            let continues_box = |delta: Point| self.map.get(point + delta) == Some(&object);
+
+            frame[point] = match object {
+                Object::Wall => Tile::new('#', Style::Wall),
+                Object::Box(_) => match (
+                    continues_box(Point::new(-1, 0)),
+                    continues_box(Point::new(1, 0)),
+                ) {
+                    (false, false) => Tile::new('O', Style::Box),
+                    (false, true) => Tile::new('[', Style::Box),
+                    (true, false) => Tile::new(']', Style::Box),
+                    (true, true) => Tile::new('=', Style::Box),
+                },
+                Object::Nothing => Tile::new('.', Style::Plain),
+                Object::Robot => Tile::new('@', Style::Actor),
+            };
+        }
+
+        frame
+    }
+}
+
+fn assign_box_ids(
+    warehouse_lines: &[&str],
+    symbols: &Grid<char>,
+) -> Result<Grid<Object>, ParseError> {
+    let mut rows: Vec<Vec<Object>> = Vec::new();
+    let mut box_count: usize = 0;
+    let mut has_robot: bool = false;
+
+    for (line_index, (line, symbols)) in warehouse_lines.iter().zip(symbols.rows()).enumerate() {
+        let mut row: Vec<Object> = Vec::new();
+
+        for (i, (&symbol, (offset, char))) in symbols.iter().zip(line.char_indices()).enumerate() {
+            let is_box_opened: bool = i > 0 && matches!(symbols[i - 1], '[' | '=');
+            let is_box_closed: bool = matches!(symbols.get(i + 1), Some('=' | ']'));
+            let invalid_value = |expected: &'static str| {
+                let token: &str = &line[offset..offset + char.len_utf8()];
+                ParseError::invalid_value(line_index, line, token, expected)
+            };
+
+            let object: Object = match symbol {
+                '#' => Object::Wall,
+                '.' => Object::Nothing,
+                '@' if has_robot => return Err(invalid_value("a single robot '@'")),
+                '@' => {
+                    has_robot = true;
+                    Object::Robot
+                }
+                '[' if !is_box_closed => return Err(invalid_value("a '[' closed by ']'")),
+                '=' if !is_box_opened || !is_box_closed => {
+                    return Err(invalid_value("'=' between '[' and ']'"))
+                }
+                ']' if !is_box_opened => return Err(invalid_value("']' closing a '['")),
+                'O' | '[' => {
+                    box_count += 1;
+                    Object::Box(box_count - 1)
+                }
+                _ => *row.last().expect("Opened box should have a previous cell"),
+            };
+            row.push(object);
+        }
+
+        rows.push(row);
+    }
+
+    Grid::from_rows(rows).ok_or(ParseError::missing_element("a warehouse map"))
+}
+
+pub(crate) fn widen(map: &Grid<Object>, scale: usize) -> Grid<Object> {
+    let rows: Vec<Vec<Object>> = map
+        .rows()
+        .map(|row| {
+            row.iter()
+                .flat_map(|&object| match object {
+                    Object::Robot => {
+                        [vec![Object::Robot], vec![Object::Nothing; scale - 1]].concat()
+                    }
+                    object => vec![object; scale],
+                })
+                .collect()
+        })
+        .collect();
+
+    Grid::from_rows(rows).expect("Widened rows should have equal length")
+}
+
+pub(crate) fn parse_warehouse(
+    file_content: &str,
+    scale: usize,
+) -> Result<(Warehouse, Vec<Direction>), ParseError> {
+    let warehouse_lines: Vec<&str> = file_content
+        .lines()
+        .take_while(|line| !line.is_empty())
+        .collect();
+
+    let symbols: Grid<char> = Grid::parse(
+        &warehouse_lines.join("\n"),
+        |char| "#O[=]@.".contains(char).then_some(char),
+        "one of '#', 'O', '[', '=', ']', '@', '.'",
+    )?;
+    let warehouse_map: Grid<Object> = widen(&assign_box_ids(&warehouse_lines, &symbols)?, scale);
+
+    let warehouse: Warehouse =
+        Warehouse::new(warehouse_map).ok_or(ParseError::missing_element("a robot '@'"))?;
+    let mut robot_movements: Vec<Direction> = Vec::new();
+
+    for (line_index, line) in file_content
+        .lines()
+        .enumerate()
+        .skip(warehouse_lines.len() + 1)
+    {
+        for (i, char) in line.char_indices() {
+            let direction: Direction = Direction::from_char(char).ok_or_else(|| {
+                let token: &str = &line[i..i + char.len_utf8()];
+                ParseError::invalid_value(line_index, line, token, "one of '>', 'v', '<', '^'")
+            })?;
+            robot_movements.push(direction);
+        }
+    }
+
+    Ok((warehouse, robot_movements))
+}
+
+pub(crate) fn format_session(warehouse: &Warehouse) -> String {
+    let mut starting_warehouse: Warehouse = warehouse.clone();
+    while starting_warehouse.undo().is_some() {}
+
+    let warehouse_lines: Vec<String> = starting_warehouse
+        .build_frame()
+        .rows()
+        .map(|row| row.iter().map(|tile| tile.symbol).collect())
+        .collect();
+    let moves: Vec<char> = warehouse.recorded_moves().chars().collect();
+    let move_lines: Vec<String> = moves
+        .chunks(MOVES_PER_LINE)
+        .map(|chunk| chunk.iter().collect())
+        .collect();
+
+    format!(
+        "{}\n\n{}\n",
+        warehouse_lines.join("\n"),
+        move_lines.join("\n")
+    )
+}
+
+pub(crate) fn render_animation(
+    warehouse: &Warehouse,
+    robot_movements: &[Direction],
+    renderer: &mut Renderer,
+) -> io::Result<()> {
+    let mut warehouse: Warehouse = warehouse.clone();
+    let chunk_size: usize = robot_movements.len().div_ceil(ANIMATION_FRAMES).max(1);
+    renderer.render(&warehouse.build_frame())?;
+
+    for movements in robot_movements.chunks(chunk_size) {
+        warehouse.run(movements);
+        renderer.render(&warehouse.build_frame())?;
+    }
+
+    Ok(())
+}
+
+fn write_screen(output: &mut impl Write, warehouse: &Warehouse, message: &str) -> io::Result<()> {
+    write!(
+        output,
+        "\x1b[2J\x1b[H{}Moves: {}, GPS sum: {}\n{}\n",
+        format_ansi_frame(&warehouse.build_frame()),
+        warehouse.step_count(),
+        warehouse.sum_gps_coordinates(),
+        message
+    )?;
+    output.flush()
+}
+
+pub(crate) fn run_session(
+    warehouse: &mut Warehouse,
+    input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<()> {
+    let mut bytes = input.bytes();
+    write_screen(&mut output, warehouse, HELP)?;
+
+    while let Some(byte) = bytes.next() {
+        let direction: Option<Direction> = match byte? {
+            b'\x1b' => match (bytes.next().transpose()?, bytes.next().transpose()?) {
+                (Some(b'['), Some(b'A')) => Some(Direction::North),
+                (Some(b'['), Some(b'B')) => Some(Direction::South),
+                (Some(b'['), Some(b'C')) => Some(Direction::East),
+                (Some(b'['), Some(b'D')) => Some(Direction::West),
+                _ => None,
+            },
+            b'u' => {
+                let message: &str = match warehouse.undo() {
+                    Some(_) => "Undid the last move",
+                    None => "Nothing to undo",
+                };
+                write_screen(&mut output, warehouse, message)?;
+                continue;
+            }
+            b'q' => break,
+            byte => Direction::from_char(byte as char),
+        };
+
+        if let Some(direction) = direction {
+            let message: &str = match warehouse.step(direction) {
+                true => HELP,
+                false => "Blocked",
+            };
+            write_screen(&mut output, warehouse, message)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_15_warehouse_woes::engine::*;
+
+    #[test]
+    fn step_undo_and_record_moves() {
+        let file_content = "#######\n#.@O..#\n#.....#\n#######\n\n>>";
+        let (mut warehouse, movements) = parse_warehouse(file_content, 1).unwrap();
+        warehouse.run(&movements);
+        assert_eq!(warehouse.sum_gps_coordinates(), 105);
+        assert!(!warehouse.step(Direction::East));
+        assert!(warehouse.step(Direction::South));
+        assert_eq!(warehouse.recorded_moves(), ">>>v");
+
+        assert_eq!(warehouse.undo(), Some(Direction::South));
+        assert_eq!(warehouse.undo(), Some(Direction::East));
+        assert_eq!(warehouse.undo(), Some(Direction::East));
+        assert_eq!(warehouse.sum_gps_coordinates(), 104);
+        assert_eq!(warehouse.undo(), Some(Direction::East));
+        assert_eq!(warehouse.undo(), None);
+        assert_eq!(warehouse.sum_gps_coordinates(), 103);
+
+        let mut output: Vec<u8> = Vec::new();
+        run_session(&mut warehouse, "v\x1b[C\x1b[Cuq>".as_bytes(), &mut output).unwrap();
+        assert_eq!(warehouse.recorded_moves(), "v>");
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .ends_with("Moves: 2, GPS sum: 103\nUndid the last move\n"));
+        assert_eq!(
+            format_session(&warehouse),
+            "#######\n#.@O..#\n#.....#\n#######\n\nv>\n"
+        );
+    }
+
+    #[test]
+    fn push_boxes_of_any_width() {
+        let file_content = "#######\n#.....#\n#.O...#\n#..O..#\n#..@..#\n#######\n\n^<^";
+        let (mut warehouse, movements) = parse_warehouse(file_content, 3).unwrap();
+        warehouse.run(&movements);
+        assert_eq!(warehouse.sum_gps_coordinates(), 315);
+        assert!(!warehouse.step(Direction::North));
+        assert!(warehouse.step(Direction::East));
+        assert_eq!(warehouse.sum_gps_coordinates(), 316);
+
+        let row: String = warehouse
+            .build_frame()
+            .rows()
+            .nth(2)
+            .unwrap()
+            .iter()
+            .map(|tile| tile.symbol)
+            .collect();
+        assert_eq!(row, "###......@[=].....###");
+
+        let frame: Vec<String> = warehouse
+            .build_frame()
+            .rows()
+            .map(|row| row.iter().map(|tile| tile.symbol).collect())
+            .collect();
+        let (reparsed, _) = parse_warehouse(&frame.join("\n"), 1).unwrap();
+        assert_eq!(reparsed.sum_gps_coordinates(), 316);
+
+        let mut warehouse = parse_warehouse(file_content, 3).unwrap().0;
+        warehouse.run(&movements);
+        warehouse.step(Direction::East);
+        let (mut replayed, replayed_movements) =
+            parse_warehouse(&format_session(&warehouse), 1).unwrap();
+        replayed.run(&replayed_movements);
+        assert_eq!(replayed_movements.len(), 4);
+        assert_eq!(replayed.sum_gps_coordinates(), 316);
+        assert_eq!(replayed.build_frame(), warehouse.build_frame());
+
+        let (warehouse, _) = parse_warehouse("#####\n#[]@#\n#####", 1).unwrap();
+        assert_eq!(warehouse.sum_gps_coordinates(), 101);
+        assert_eq!(
+            parse_warehouse("####\n#.]@\n####", 1)
+                .unwrap_err()
+                .to_string(),
+            "Line 2, column 3: invalid value ']', expected ']' closing a '['"
+        );
+        assert_eq!(
+            parse_warehouse("####\n#[.@\n####", 1)
+                .unwrap_err()
+                .to_string(),
+            "Line 2, column 2: invalid value '[', expected a '[' closed by ']'"
+        );
+        assert_eq!(
+            parse_warehouse("#####\n#@@.#\n#####\n\n<>", 1)
+                .unwrap_err()
+                .to_string(),
+            "Line 2, column 3: invalid value '@', expected a single robot '@'"
+        );
+    }
+}
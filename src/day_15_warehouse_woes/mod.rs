@@ -1,2 +1,64 @@
+use crate::cli::WarehouseOptions;
+use crate::solution::Registry;
+use std::process::{Command, Stdio};
+
+pub(crate) mod engine;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+
+pub(crate) fn register(registry: &mut Registry) {
+    registry.register(part_1::Part1);
+    registry.register(part_2::Part2);
+}
+
+fn set_terminal_line_mode(enabled: bool) -> bool {
+    let arguments: [&str; 2] = match enabled {
+        true => ["icanon", "echo"],
+        false => ["-icanon", "-echo"],
+    };
+
+    Command::new("stty")
+        .args(arguments)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+pub(crate) fn run_warehouse_command(options: &WarehouseOptions, file_content: &str) -> bool {
+    let mut warehouse: engine::Warehouse =
+        match engine::parse_warehouse(file_content, options.scale) {
+            Ok((warehouse, _)) => warehouse,
+            Err(error) => {
+                eprintln!("{}", error);
+                return false;
+            }
+        };
+
+    let raw_keys: bool = set_terminal_line_mode(false);
+    let session = engine::run_session(&mut warehouse, std::io::stdin().lock(), std::io::stdout());
+
+    if raw_keys {
+        set_terminal_line_mode(true);
+    }
+
+    if let Err(error) = session {
+        eprintln!("{}", error);
+        return false;
+    }
+
+    let recording: String = engine::format_session(&warehouse);
+
+    match &options.record {
+        Some(file_path) => match std::fs::write(file_path, recording) {
+            Ok(()) => println!("Recorded {} moves to {}", warehouse.step_count(), file_path),
+            Err(error) => {
+                eprintln!("Failed to write '{}': {}", file_path, error);
+                return false;
+            }
+        },
+        None => println!("Moves: {}", warehouse.recorded_moves()),
+    }
+
+    true
+}
@@ -0,0 +1,188 @@
+use crate::day_16_reindeer_maze::routes::Move;
+use crate::direction::Direction;
+use crate::grid::{Grid, Point};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct CostModel {
+    forward: u32,
+    turn_left: u32,
+    turn_right: u32,
+    u_turn: u32,
+    start_heading: Direction,
+    end_heading: Option<Direction>,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum CostModelError {
+    FreeForwardMove,
+}
+
+impl fmt::Display for CostModelError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CostModelError::FreeForwardMove => write!(
+                f,
+                "moving forward must cost at least 1, otherwise routes can loop forever"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CostModelError {}
+
+impl Default for CostModel {
+    fn default() -> Self {
+        CostModel::new(1, 1000, 1000, 2000)
+            .expect("Default costs should be valid")
+            .with_headings(Direction::East, None)
+    }
+}
+
+impl CostModel {
+    pub(crate) fn new(
+        forward: u32,
+        turn_left: u32,
+        turn_right: u32,
+        u_turn: u32,
+    ) -> Result<Self, CostModelError> {
+        if forward == 0 {
+            return Err(CostModelError::FreeForwardMove);
+        }
+
+        Ok(CostModel {
+            forward,
+            turn_left,
+            turn_right,
+            u_turn,
+            start_heading: Direction::East,
+            end_heading: None,
+        })
+    }
+
+    pub(crate) fn with_headings(
+        self,
+        start_heading: Direction,
+        end_heading: Option<Direction>,
+    ) -> Self {
+        CostModel {
+            start_heading,
+            end_heading,
+            ..self
+        }
+    }
+
+    pub(crate) fn start_heading(&self) -> Direction {
+        self.start_heading
+    }
+
+    pub(crate) fn move_cost(&self, step: Move) -> u32 {
+        match step {
+            Move::Forward => self.forward,
+            Move::TurnLeft => self.turn_left,
+            Move::TurnRight => self.turn_right,
+            Move::UTurn => self.u_turn,
+        }
+    }
+
+    fn turn_cost(&self, from: Direction, to: Direction) -> u32 {
+        if to == from.rotate_left() {
+            self.turn_left
+        } else if to == from.rotate_right() {
+            self.turn_right
+        } else if to == from.opposite() {
+            self.u_turn
+        } else {
+            0
+        }
+    }
+
+    pub(crate) fn end_headings(&self) -> Vec<Direction> {
+        match self.end_heading {
+            Some(heading) => vec![heading],
+            None => Direction::ALL.to_vec(),
+        }
+    }
+}
+
+pub(crate) fn get_next_states<'a>(
+    maze: &'a Grid<char>,
+    cost_model: &CostModel,
+    end: Point,
+    (position, dir): (Point, Direction),
+) -> impl Iterator<Item = ((Point, Direction), u32)> + 'a {
+    let turn_at_end: Option<((Point, Direction), u32)> = cost_model
+        .end_heading
+        .filter(|&heading| position == end && heading != dir)
+        .map(|heading| ((end, heading), cost_model.turn_cost(dir, heading)));
+
+    [dir, dir.rotate_left(), dir.rotate_right(), dir.opposite()]
+        .map(|next_dir| {
+            (
+                next_dir,
+                cost_model.turn_cost(dir, next_dir) + cost_model.forward,
+            )
+        })
+        .into_iter()
+        .map(move |(next_dir, cost)| ((position + next_dir.delta(), next_dir), cost))
+        .filter(|&((next, _), _)| maze.get(next).is_some_and(|&symbol| symbol != '#'))
+        .chain(turn_at_end)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_16_reindeer_maze::cost::*;
+    use crate::day_16_reindeer_maze::part_1::{find_cheapest_path, process_data};
+
+    #[test]
+    fn solve_with_custom_costs() {
+        let maze = process_data("######\n#E..S#\n##.###\n######").unwrap();
+        let [start, end] = [Point::new(4, 1), Point::new(1, 1)];
+        let cost_model = CostModel::default();
+        assert_eq!(
+            find_cheapest_path(&maze, start, end, &cost_model),
+            Some(2003)
+        );
+
+        let cost_model = CostModel::new(1, 1000, 1000, 5).unwrap();
+        assert_eq!(find_cheapest_path(&maze, start, end, &cost_model), Some(8));
+
+        let cost_model = CostModel::default().with_headings(Direction::West, Some(Direction::East));
+        assert_eq!(
+            find_cheapest_path(&maze, start, end, &cost_model),
+            Some(2003)
+        );
+
+        let end = Point::new(2, 2);
+        let cost_model = CostModel::new(1, 10, 20, 2000)
+            .unwrap()
+            .with_headings(Direction::West, None);
+        assert_eq!(find_cheapest_path(&maze, start, end, &cost_model), Some(13));
+
+        let cost_model = CostModel::new(1, 3000, 20, 2000)
+            .unwrap()
+            .with_headings(Direction::West, None);
+        assert_eq!(
+            find_cheapest_path(&maze, start, end, &cost_model),
+            Some(2025)
+        );
+
+        let maze = process_data("S.E").unwrap();
+        let [start, end] = [Point::new(0, 0), Point::new(2, 0)];
+        assert_eq!(
+            find_cheapest_path(&maze, start, end, &CostModel::default()),
+            Some(2)
+        );
+
+        let maze = process_data("S#E").unwrap();
+        assert_eq!(
+            find_cheapest_path(&maze, start, end, &CostModel::default()),
+            None
+        );
+
+        assert_eq!(
+            CostModel::new(0, 1000, 1000, 2000),
+            Err(CostModelError::FreeForwardMove)
+        );
+    }
+}
@@ -1,2 +1,60 @@
+use crate::cli::RoutesOptions;
+use crate::grid::Grid;
+use crate::solution::Registry;
+
+pub(crate) mod cost;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod routes;
+
+pub(crate) fn register(registry: &mut Registry) {
+    registry.register(part_1::Part1);
+    registry.register(part_2::Part2);
+}
+
+pub(crate) fn run_routes_command(options: &RoutesOptions, file_content: &str) -> bool {
+    let maze: Grid<char> = match part_1::process_data(file_content) {
+        Ok(maze) => maze,
+        Err(error) => {
+            eprintln!("{}", error);
+            return false;
+        }
+    };
+
+    let Some([start, end]) = part_1::find_start_and_end(&maze) else {
+        eprintln!("The maze is missing a start 'S' and an end 'E'");
+        return false;
+    };
+
+    let cost_model: cost::CostModel = cost::CostModel::default();
+    let mut routes: Vec<routes::Route> =
+        routes::find_optimal_routes(&maze, start, end, &cost_model, options.max_routes + 1);
+    let is_truncated: bool = routes.len() > options.max_routes;
+    routes.truncate(options.max_routes);
+
+    let Some(score) = routes.first().map(|route| route.score(&cost_model)) else {
+        eprintln!("The end cannot be reached from the start");
+        return false;
+    };
+
+    match is_truncated {
+        true => println!(
+            "First {} optimal routes with a score of {} (raise --max-routes to see more)",
+            routes.len(),
+            score
+        ),
+        false => println!("{} optimal routes with a score of {}", routes.len(), score),
+    }
+
+    for (index, route) in routes.iter().enumerate() {
+        println!(
+            "\nRoute {}: {} steps, {} turns\n{}",
+            index + 1,
+            route.forward_steps(),
+            route.turns(),
+            route
+        );
+    }
+
+    true
+}
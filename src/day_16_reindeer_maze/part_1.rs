@@ -1,108 +1,107 @@
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashSet};
-
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone, Hash)]
-enum Dir {
-    East,
-    South,
-    West,
-    North,
+use crate::day_16_reindeer_maze::cost::{get_next_states, CostModel};
+use crate::direction::Direction;
+use crate::grid::{Grid, Point};
+use crate::parsing::ParseError;
+use crate::render::{Renderer, Style, Tile};
+use crate::search;
+use crate::solution::{Answer, Solution};
+use std::io;
+
+pub(crate) fn process_data(file_content: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(
+        file_content,
+        |char| "#.SE".contains(char).then_some(char),
+        "one of '#', '.', 'S', 'E'",
+    )
 }
 
-impl Dir {
-    fn rotate_left(&self) -> Self {
-        match self {
-            Dir::East => Dir::North,
-            Dir::North => Dir::West,
-            Dir::West => Dir::South,
-            Dir::South => Dir::East,
-        }
-    }
-
-    fn rotate_right(&self) -> Self {
-        match self {
-            Dir::East => Dir::South,
-            Dir::South => Dir::West,
-            Dir::West => Dir::North,
-            Dir::North => Dir::East,
-        }
-    }
-
-    fn get_dx_dy(&self) -> (i32, i32) {
-        match self {
-            Dir::East => (1, 0),
-            Dir::South => (0, 1),
-            Dir::West => (-1, 0),
-            Dir::North => (0, -1),
-        }
-    }
+pub(crate) fn find_start_and_end(maze: &Grid<char>) -> Option<[Point; 2]> {
+    Some([maze.find(&'S')?, maze.find(&'E')?])
 }
 
-fn process_data(file_path: &str) -> Vec<Vec<char>> {
-    let file_content = std::fs::read_to_string(file_path).expect("Failed to open file");
+pub(crate) fn find_cheapest_path(
+    maze: &Grid<char>,
+    start: Point,
+    end: Point,
+    cost_model: &CostModel,
+) -> Option<u32> {
+    let result = search::dijkstra((start, cost_model.start_heading()), |state| {
+        get_next_states(maze, cost_model, end, state)
+    });
+
+    let end_headings: Vec<Direction> = cost_model.end_headings();
+
+    result
+        .distances()
+        .filter(|&(&(position, dir), _)| position == end && end_headings.contains(&dir))
+        .map(|(_, &score)| score)
+        .min()
+}
 
-    file_content
-        .lines()
-        .map(|line| line.chars().collect())
+fn find_cheapest_route(
+    maze: &Grid<char>,
+    start: Point,
+    end: Point,
+    cost_model: &CostModel,
+) -> Vec<Point> {
+    let result = search::dijkstra((start, cost_model.start_heading()), |state| {
+        get_next_states(maze, cost_model, end, state)
+    });
+    let best_end_state: Option<(Point, Direction)> = cost_model
+        .end_headings()
+        .into_iter()
+        .filter_map(|dir| Some(((end, dir), result.distance(&(end, dir))?)))
+        .min_by_key(|&(_, score)| score)
+        .map(|(state, _)| state);
+
+    best_end_state
+        .and_then(|state| result.path_to(state))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(position, _)| position)
         .collect()
 }
 
-fn find_start_and_end(maze: &[Vec<char>]) -> Option<[(i32, i32); 2]> {
-    let mut start: Option<(i32, i32)> = None;
-    let mut end: Option<(i32, i32)> = None;
+pub(crate) struct Part1;
 
-    for (y, row) in maze.iter().enumerate() {
-        for (x, &char) in row.iter().enumerate() {
-            match char {
-                'S' => start = Some((x as i32, y as i32)),
-                'E' => end = Some((x as i32, y as i32)),
-                _ => {}
-            }
-        }
-    }
+impl Solution for Part1 {
+    type Parsed = (Grid<char>, [Point; 2]);
+
+    const DAY: u8 = 16;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Reindeer Maze";
 
-    match [start, end] {
-        [Some(start), Some(end)] => Some([start, end]),
-        _ => None,
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let maze: Grid<char> = process_data(input)?;
+        let [start, end]: [Point; 2] = find_start_and_end(&maze)
+            .ok_or(ParseError::missing_element("a start 'S' and an end 'E'"))?;
+        Ok((maze, [start, end]))
     }
-}
 
-fn find_cheapest_path(maze: &[Vec<char>], start: (i32, i32), end: (i32, i32), dir: &Dir) -> u32 {
-    let mut p_queue: BinaryHeap<(Reverse<u32>, (i32, i32), Dir)> =
-        BinaryHeap::from([(Reverse(0), start, *dir)]);
-    let mut seen: HashSet<((i32, i32), Dir)> = HashSet::new();
-    let mut scores: Vec<u32> = Vec::new();
+    fn solve(
+        &self,
+        (maze, [start, end]): &Self::Parsed,
+    ) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: u32 = find_cheapest_path(maze, *start, *end, &CostModel::default())
+            .ok_or("The end cannot be reached from the start")?;
+        Ok(Answer::from(result))
+    }
 
-    while let Some((Reverse(score), (x, y), dir)) = p_queue.pop() {
-        if (x, y) == end {
-            scores.push(score);
-        }
+    fn visualize(
+        &self,
+        (maze, [start, end]): &Self::Parsed,
+        renderer: &mut Renderer,
+    ) -> io::Result<()> {
+        let mut frame: Grid<Tile> = maze.map(|&symbol| Tile::from_symbol(symbol));
 
-        if seen.insert(((x, y), dir)) {
-            for (next_dir, next_score) in [
-                (dir, score + 1),
-                (dir.rotate_left(), score + 1001),
-                (dir.rotate_right(), score + 1001),
-            ] {
-                let (dx, dy) = next_dir.get_dx_dy();
-                let (nx, ny) = (x + dx, y + dy);
-
-                if maze[ny as usize][nx as usize] != '#' {
-                    p_queue.push((Reverse(next_score), (nx, ny), next_dir));
-                }
+        for position in find_cheapest_route(maze, *start, *end, &CostModel::default()) {
+            if maze[position] == '.' {
+                frame[position] = Tile::new('O', Style::Path);
             }
         }
-    }
 
-    *scores.iter().min().unwrap_or(&0)
-}
-
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let maze: Vec<Vec<char>> = process_data("./input/16.txt");
-    let [start, end]: [(i32, i32); 2] =
-        find_start_and_end(&maze).expect("Start and end position should be provided");
-    let result: u32 = find_cheapest_path(&maze, start, end, &Dir::East);
-    Ok(format!("Day 16 Reindeer Maze (Part 1): {}.", result))
+        renderer.render(&frame)
+    }
 }
 
 #[cfg(test)]
@@ -111,14 +110,16 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let maze = process_data("./test_input/16_01.txt");
+        let file_content = std::fs::read_to_string("./test_input/16_01.txt").unwrap();
+        let maze = process_data(&file_content).unwrap();
         let [start, end] = find_start_and_end(&maze).unwrap();
-        let result = find_cheapest_path(&maze, start, end, &Dir::East);
-        assert_eq!(result, 7036);
+        let result = find_cheapest_path(&maze, start, end, &CostModel::default());
+        assert_eq!(result, Some(7036));
 
-        let maze = process_data("./test_input/16_02.txt");
+        let file_content = std::fs::read_to_string("./test_input/16_02.txt").unwrap();
+        let maze = process_data(&file_content).unwrap();
         let [start, end] = find_start_and_end(&maze).unwrap();
-        let result = find_cheapest_path(&maze, start, end, &Dir::East);
-        assert_eq!(result, 11048);
+        let result = find_cheapest_path(&maze, start, end, &CostModel::default());
+        assert_eq!(result, Some(11048));
     }
 }
@@ -1,175 +1,140 @@
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashMap, HashSet};
-
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone, Hash)]
-enum Dir {
-    East,
-    South,
-    West,
-    North,
+use crate::day_16_reindeer_maze::cost::{get_next_states, CostModel};
+use crate::direction::Direction;
+use crate::grid::{Grid, Point};
+use crate::parsing::ParseError;
+use crate::render::{Renderer, Style, Tile};
+use crate::search::{self, SearchResult};
+use crate::solution::{Answer, Solution};
+use std::collections::HashSet;
+use std::io;
+
+fn process_data(file_content: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(
+        file_content,
+        |char| "#.SE".contains(char).then_some(char),
+        "one of '#', '.', 'S', 'E'",
+    )
 }
 
-impl Dir {
-    fn rotate_left(&self) -> Self {
-        match self {
-            Dir::East => Dir::North,
-            Dir::North => Dir::West,
-            Dir::West => Dir::South,
-            Dir::South => Dir::East,
-        }
-    }
-
-    fn rotate_right(&self) -> Self {
-        match self {
-            Dir::East => Dir::South,
-            Dir::South => Dir::West,
-            Dir::West => Dir::North,
-            Dir::North => Dir::East,
-        }
-    }
-
-    fn get_dx_dy(&self) -> [i32; 2] {
-        match self {
-            Dir::East => [1, 0],
-            Dir::South => [0, 1],
-            Dir::West => [-1, 0],
-            Dir::North => [0, -1],
-        }
-    }
+fn find_start_and_end(maze: &Grid<char>) -> Option<[Point; 2]> {
+    Some([maze.find(&'S')?, maze.find(&'E')?])
 }
 
-fn process_data(file_path: &str) -> Vec<Vec<char>> {
-    let file_content = std::fs::read_to_string(file_path).expect("Failed to open file");
-
-    file_content
-        .lines()
-        .map(|line| line.chars().collect())
+fn find_positions_on_best_paths(
+    maze: &Grid<char>,
+    start: Point,
+    end: Point,
+    cost_model: &CostModel,
+) -> HashSet<Point> {
+    let result: SearchResult<(Point, Direction)> =
+        search::dijkstra((start, cost_model.start_heading()), |state| {
+            get_next_states(maze, cost_model, end, state)
+        });
+
+    let end_states: Vec<((Point, Direction), u32)> = cost_model
+        .end_headings()
+        .into_iter()
+        .filter_map(|dir| Some(((end, dir), result.distance(&(end, dir))?)))
+        .collect();
+    let lowest_score: Option<u32> = end_states.iter().map(|&(_, score)| score).min();
+    let best_end_states: Vec<(Point, Direction)> = end_states
+        .into_iter()
+        .filter(|&(_, score)| Some(score) == lowest_score)
+        .map(|(state, _)| state)
+        .collect();
+
+    result
+        .states_on_shortest_paths(&best_end_states)
+        .into_iter()
+        .map(|(position, _)| position)
         .collect()
 }
 
-fn find_start_and_end(maze: &[Vec<char>]) -> Option<[[i32; 2]; 2]> {
-    let mut start: Option<[i32; 2]> = None;
-    let mut end: Option<[i32; 2]> = None;
-
-    for (y, row) in maze.iter().enumerate() {
-        for (x, &char) in row.iter().enumerate() {
-            match char {
-                'S' => start = Some([x as i32, y as i32]),
-                'E' => end = Some([x as i32, y as i32]),
-                _ => {}
-            }
+fn find_tiles_on_best_paths(
+    maze: &Grid<char>,
+    start: Point,
+    end: Point,
+    cost_model: &CostModel,
+) -> Option<usize> {
+    let positions: HashSet<Point> = find_positions_on_best_paths(maze, start, end, cost_model);
+    (!positions.is_empty()).then_some(positions.len())
+}
+
+fn build_frame(maze: &Grid<char>, positions: &HashSet<Point>) -> Grid<Tile> {
+    let mut frame: Grid<Tile> = maze.map(|&symbol| Tile::from_symbol(symbol));
+
+    for &position in positions {
+        if maze[position] == '.' {
+            frame[position] = Tile::new('O', Style::Path);
         }
     }
 
-    match [start, end] {
-        [Some(start), Some(end)] => Some([start, end]),
-        _ => None,
-    }
+    frame
 }
 
-fn find_tiles_on_best_paths(
-    maze: &[Vec<char>],
-    start: [i32; 2],
-    end: [i32; 2],
-    dir: &Dir,
-) -> usize {
-    let mut p_queue: BinaryHeap<(Reverse<u32>, [i32; 2], Dir)> =
-        BinaryHeap::from([(Reverse(0), start, *dir)]);
-    let mut backtrack = HashMap::new();
-    let mut end_lowest_score: u32 = u32::MAX;
-    let mut end_states: HashSet<([i32; 2], Dir)> = HashSet::new();
-    let mut lowest_scores: HashMap<([i32; 2], Dir), u32> = HashMap::new();
-
-    while let Some((Reverse(score), [x, y], dir)) = p_queue.pop() {
-        if score > *lowest_scores.entry(([x, y], dir)).or_insert(u32::MAX) {
-            continue;
-        }
+pub(crate) struct Part2;
 
-        if [x, y] == end {
-            if score < end_lowest_score {
-                end_lowest_score = score;
-                end_states.insert(([x, y], dir));
-            }
+impl Solution for Part2 {
+    type Parsed = (Grid<char>, [Point; 2]);
 
-            continue;
-        }
+    const DAY: u8 = 16;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Reindeer Maze";
 
-        for (next_dir, next_score) in [
-            (dir, score + 1),
-            (dir.rotate_left(), score + 1001),
-            (dir.rotate_right(), score + 1001),
-        ] {
-            let [dx, dy] = next_dir.get_dx_dy();
-            let [nx, ny] = [x + dx, y + dy];
-
-            if maze[ny as usize][nx as usize] == '#' {
-                continue;
-            }
-
-            let lowest_score = lowest_scores
-                .entry(([nx, ny], next_dir))
-                .or_insert(u32::MAX);
-
-            if next_score > *lowest_score {
-                continue;
-            }
-
-            if next_score < *lowest_score {
-                lowest_scores
-                    .entry(([nx, ny], next_dir))
-                    .and_modify(|value| *value = next_score);
-            }
-
-            backtrack
-                .entry(([nx, ny], next_dir))
-                .or_insert(HashSet::new())
-                .insert(([x, y], dir));
-
-            p_queue.push((Reverse(next_score), [nx, ny], next_dir));
-        }
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        let maze: Grid<char> = process_data(input)?;
+        let [start, end]: [Point; 2] = find_start_and_end(&maze)
+            .ok_or(ParseError::missing_element("a start 'S' and an end 'E'"))?;
+        Ok((maze, [start, end]))
     }
 
-    let mut states: Vec<([i32; 2], Dir)> = end_states.clone().into_iter().collect();
-    let mut seen: HashSet<([i32; 2], Dir)> = end_states;
-
-    while let Some(current_state) = states.pop() {
-        if let Some(previous_states) = backtrack.get(&current_state) {
-            for &([x, y], dir) in previous_states {
-                if seen.insert(([x, y], dir)) {
-                    states.push(([x, y], dir));
-                }
-            }
-        }
+    fn solve(
+        &self,
+        (maze, [start, end]): &Self::Parsed,
+    ) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: usize = find_tiles_on_best_paths(maze, *start, *end, &CostModel::default())
+            .ok_or("The end cannot be reached from the start")?;
+        Ok(Answer::from(result))
     }
 
-    seen.iter()
-        .map(|([x, y], _)| [*x, *y])
-        .collect::<HashSet<[i32; 2]>>()
-        .len()
-}
-
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let maze: Vec<Vec<char>> = process_data("./input/16.txt");
-    let [start, end]: [[i32; 2]; 2] =
-        find_start_and_end(&maze).expect("Start and end position should be provided");
-    let result: usize = find_tiles_on_best_paths(&maze, start, end, &Dir::East);
-    Ok(format!("Day 16 Reindeer Maze (Part 2): {}.", result))
+    fn visualize(
+        &self,
+        (maze, [start, end]): &Self::Parsed,
+        renderer: &mut Renderer,
+    ) -> io::Result<()> {
+        let positions: HashSet<Point> =
+            find_positions_on_best_paths(maze, *start, *end, &CostModel::default());
+        renderer.render(&build_frame(maze, &positions))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::day_16_reindeer_maze::part_2::*;
+    use crate::day_16_reindeer_maze::routes::find_optimal_routes;
 
     #[test]
     fn solve_with_test_data() {
-        let maze = process_data("./test_input/16_01.txt");
+        let file_content = std::fs::read_to_string("./test_input/16_01.txt").unwrap();
+        let maze = process_data(&file_content).unwrap();
         let [start, end] = find_start_and_end(&maze).unwrap();
-        let result = find_tiles_on_best_paths(&maze, start, end, &Dir::East);
-        assert_eq!(result, 45);
+        let result = find_tiles_on_best_paths(&maze, start, end, &CostModel::default());
+        assert_eq!(result, Some(45));
 
-        let maze = process_data("./test_input/16_02.txt");
+        let file_content = std::fs::read_to_string("./test_input/16_02.txt").unwrap();
+        let maze = process_data(&file_content).unwrap();
         let [start, end] = find_start_and_end(&maze).unwrap();
-        let result = find_tiles_on_best_paths(&maze, start, end, &Dir::East);
-        assert_eq!(result, 64);
+        let result = find_tiles_on_best_paths(&maze, start, end, &CostModel::default());
+        assert_eq!(result, Some(64));
+
+        let positions_on_routes: HashSet<Point> =
+            find_optimal_routes(&maze, start, end, &CostModel::default(), usize::MAX)
+                .into_iter()
+                .flat_map(|route| route.positions)
+                .collect();
+        assert_eq!(
+            positions_on_routes,
+            find_positions_on_best_paths(&maze, start, end, &CostModel::default())
+        );
     }
 }
@@ -0,0 +1,161 @@
+use crate::day_16_reindeer_maze::cost::{get_next_states, CostModel};
+use crate::direction::Direction;
+use crate::grid::{Grid, Point};
+use crate::search::{self, SearchResult};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Move {
+    Forward,
+    TurnLeft,
+    TurnRight,
+    UTurn,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct Route {
+    pub(crate) moves: Vec<Move>,
+    pub(crate) positions: Vec<Point>,
+}
+
+impl Route {
+    fn from_states(states: &[(Point, Direction)]) -> Self {
+        let mut moves: Vec<Move> = Vec::new();
+
+        for pair in states.windows(2) {
+            let [(position, dir), (next_position, next_dir)] = [pair[0], pair[1]];
+
+            if next_dir == dir.rotate_left() {
+                moves.push(Move::TurnLeft);
+            } else if next_dir == dir.rotate_right() {
+                moves.push(Move::TurnRight);
+            } else if next_dir == dir.opposite() {
+                moves.push(Move::UTurn);
+            }
+
+            if next_position != position {
+                moves.push(Move::Forward);
+            }
+        }
+
+        let mut positions: Vec<Point> = states.iter().map(|&(position, _)| position).collect();
+        positions.dedup();
+
+        Route { moves, positions }
+    }
+
+    pub(crate) fn forward_steps(&self) -> usize {
+        self.moves
+            .iter()
+            .filter(|&&step| step == Move::Forward)
+            .count()
+    }
+
+    pub(crate) fn turns(&self) -> usize {
+        self.moves.len() - self.forward_steps()
+    }
+
+    pub(crate) fn score(&self, cost_model: &CostModel) -> u32 {
+        self.moves
+            .iter()
+            .map(|&step| cost_model.move_cost(step))
+            .sum()
+    }
+}
+
+impl fmt::Display for Route {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts: Vec<String> = Vec::new();
+        let mut forward_run: usize = 0;
+
+        for &step in &self.moves {
+            if step == Move::Forward {
+                forward_run += 1;
+                continue;
+            }
+
+            if forward_run > 0 {
+                parts.push(format!("F{}", forward_run));
+                forward_run = 0;
+            }
+
+            parts.push(String::from(match step {
+                Move::TurnLeft => "L",
+                Move::TurnRight => "R",
+                _ => "U",
+            }));
+        }
+
+        if forward_run > 0 {
+            parts.push(format!("F{}", forward_run));
+        }
+
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+pub(crate) fn find_optimal_routes(
+    maze: &Grid<char>,
+    start: Point,
+    end: Point,
+    cost_model: &CostModel,
+    max_routes: usize,
+) -> Vec<Route> {
+    let result: SearchResult<(Point, Direction)> =
+        search::dijkstra((start, cost_model.start_heading()), |state| {
+            get_next_states(maze, cost_model, end, state)
+        });
+    let lowest_score: Option<u32> = cost_model
+        .end_headings()
+        .into_iter()
+        .filter_map(|dir| result.distance(&(end, dir)))
+        .min();
+
+    cost_model
+        .end_headings()
+        .into_iter()
+        .filter(|&dir| lowest_score.is_some() && result.distance(&(end, dir)) == lowest_score)
+        .flat_map(|dir| result.paths_to((end, dir)))
+        .take(max_routes)
+        .map(|states| Route::from_states(&states))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_16_reindeer_maze::part_1::{find_start_and_end, process_data};
+    use crate::day_16_reindeer_maze::routes::*;
+
+    #[test]
+    fn enumerate_optimal_routes() {
+        let file_content = std::fs::read_to_string("./test_input/16_01.txt").unwrap();
+        let maze = process_data(&file_content).unwrap();
+        let [start, end] = find_start_and_end(&maze).unwrap();
+        let routes = find_optimal_routes(&maze, start, end, &CostModel::default(), 10);
+        assert_eq!(routes.len(), 3);
+        assert_eq!(
+            find_optimal_routes(&maze, start, end, &CostModel::default(), 2).len(),
+            2
+        );
+
+        for route in &routes {
+            assert_eq!(route.score(&CostModel::default()), 7036);
+            assert_eq!((route.forward_steps(), route.turns()), (36, 7));
+            assert_eq!(route.positions.first(), Some(&start));
+            assert_eq!(route.positions.last(), Some(&end));
+        }
+
+        let maze = process_data("#####\n#..E#\n#S#.#\n#####").unwrap();
+        let [start, end] = find_start_and_end(&maze).unwrap();
+        let routes = find_optimal_routes(&maze, start, end, &CostModel::default(), 10);
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].to_string(), "L F1 R F2");
+
+        let cost_model =
+            CostModel::default().with_headings(Direction::East, Some(Direction::South));
+        let routes = find_optimal_routes(&maze, start, end, &cost_model, 10);
+        assert_eq!(routes[0].to_string(), "L F1 R F2 R");
+        assert_eq!(routes[0].score(&cost_model), 3003);
+        assert_eq!(routes[0].positions.len(), 4);
+    }
+}
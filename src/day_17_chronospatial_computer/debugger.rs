@@ -0,0 +1,294 @@
+use crate::day_17_chronospatial_computer::vm::{Instruction, Program, Vm, VmError};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+const HELP: &str = "\
+Commands:
+  s, step [N]       Execute N instructions (default: 1)
+  c, continue       Run until a breakpoint or the end of the program
+  b, break <ADDR>   Set a breakpoint at ADDR
+  d, delete <ADDR>  Remove the breakpoint at ADDR
+  r, registers      Show registers, instruction pointer and output
+  l, list           Show the program with the current instruction and breakpoints
+  t, trace          Show every executed instruction
+  h, help           Show this message
+  q, quit           Leave the debugger";
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TraceEntry {
+    pub(crate) address: usize,
+    pub(crate) instruction: Instruction,
+    pub(crate) registers: [u128; 3],
+}
+
+impl fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [a, b, c]: [u128; 3] = self.registers;
+        let instruction: String = self.instruction.to_string();
+        write!(
+            f,
+            "{:>2}: {:<6} A={} B={} C={}",
+            self.address, instruction, a, b, c
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum StopReason {
+    Breakpoint(usize),
+    Halted,
+    Error(VmError),
+}
+
+pub(crate) struct Debugger<'a> {
+    vm: Vm<'a, u128>,
+    breakpoints: BTreeSet<usize>,
+    trace: Vec<TraceEntry>,
+}
+
+impl<'a> Debugger<'a> {
+    pub(crate) fn new(program: &'a Program) -> Self {
+        Debugger {
+            vm: Vm::new(program.registers, &program.code),
+            breakpoints: BTreeSet::new(),
+            trace: Vec::new(),
+        }
+    }
+
+    pub(crate) fn vm(&self) -> &Vm<'a, u128> {
+        &self.vm
+    }
+
+    pub(crate) fn trace(&self) -> &[TraceEntry] {
+        &self.trace
+    }
+
+    pub(crate) fn add_breakpoint(&mut self, address: usize) -> bool {
+        self.breakpoints.insert(address)
+    }
+
+    pub(crate) fn remove_breakpoint(&mut self, address: usize) -> bool {
+        self.breakpoints.remove(&address)
+    }
+
+    pub(crate) fn has_breakpoint(&self, address: usize) -> bool {
+        self.breakpoints.contains(&address)
+    }
+
+    pub(crate) fn step(&mut self) -> Result<Option<&TraceEntry>, VmError> {
+        let address: usize = self.vm.instruction_pointer();
+        let Some(instruction) = self.vm.step()? else {
+            return Ok(None);
+        };
+
+        self.trace.push(TraceEntry {
+            address,
+            instruction,
+            registers: self.vm.registers(),
+        });
+        Ok(self.trace.last())
+    }
+
+    pub(crate) fn continue_execution(&mut self) -> StopReason {
+        loop {
+            match self.step() {
+                Ok(Some(_)) => {}
+                Ok(None) => return StopReason::Halted,
+                Err(error) => return StopReason::Error(error),
+            }
+
+            let address: usize = self.vm.instruction_pointer();
+
+            if self.has_breakpoint(address) {
+                return StopReason::Breakpoint(address);
+            }
+        }
+    }
+}
+
+fn format_state(vm: &Vm<u128>) -> String {
+    let [a, b, c]: [u128; 3] = vm.registers();
+    let output: Vec<String> = vm.output().iter().map(|value| value.to_string()).collect();
+    let next: String = match vm.current_instruction() {
+        Some(instruction) => instruction.to_string(),
+        None => String::from("halted"),
+    };
+
+    format!(
+        "ip={} A={} B={} C={} next: {} output: {}",
+        vm.instruction_pointer(),
+        a,
+        b,
+        c,
+        next,
+        output.join(",")
+    )
+}
+
+fn format_listing(debugger: &Debugger, program: &Program) -> String {
+    let mut lines: Vec<String> = Vec::new();
+
+    for address in (0..program.code.len()).step_by(2) {
+        let Some(instruction) = Instruction::decode(&program.code, address) else {
+            continue;
+        };
+
+        let current: char = match debugger.vm().instruction_pointer() == address {
+            true => '>',
+            false => ' ',
+        };
+        let breakpoint: char = match debugger.has_breakpoint(address) {
+            true => '*',
+            false => ' ',
+        };
+
+        lines.push(format!(
+            "{}{}{:>2}: {}",
+            current, breakpoint, address, instruction
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn parse_address(argument: Option<&str>) -> Result<usize, String> {
+    let argument: &str = argument.ok_or("Missing address")?;
+    argument
+        .parse()
+        .map_err(|_| format!("Invalid address: '{}'", argument))
+}
+
+pub(crate) fn run_session(
+    program: &Program,
+    breakpoints: &[usize],
+    input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<()> {
+    let mut debugger = Debugger::new(program);
+
+    for &address in breakpoints {
+        debugger.add_breakpoint(address);
+    }
+
+    writeln!(output, "{}", format_state(debugger.vm()))?;
+
+    for line in input.lines() {
+        let line: String = line?;
+        let mut arguments = line.split_whitespace();
+
+        let message: String = match (arguments.next(), arguments.next()) {
+            (None, _) => continue,
+            (Some("s" | "step"), count) => {
+                let count: usize = match count.map(str::parse).unwrap_or(Ok(1)) {
+                    Ok(count) => count,
+                    Err(_) => {
+                        writeln!(output, "Invalid count: '{}'", count.unwrap_or_default())?;
+                        continue;
+                    }
+                };
+                let mut lines: Vec<String> = Vec::new();
+
+                for _ in 0..count {
+                    match debugger.step() {
+                        Ok(Some(entry)) => lines.push(entry.to_string()),
+                        Ok(None) => break,
+                        Err(error) => {
+                            lines.push(format!("Error: {}", error));
+                            break;
+                        }
+                    }
+                }
+
+                lines.push(format_state(debugger.vm()));
+                lines.join("\n")
+            }
+            (Some("c" | "continue"), _) => match debugger.continue_execution() {
+                StopReason::Breakpoint(address) => {
+                    format!("Breakpoint at {}\n{}", address, format_state(debugger.vm()))
+                }
+                StopReason::Halted => format!("Halted\n{}", format_state(debugger.vm())),
+                StopReason::Error(error) => {
+                    format!("Error: {}\n{}", error, format_state(debugger.vm()))
+                }
+            },
+            (Some("b" | "break"), address) => match parse_address(address) {
+                Ok(address) => match debugger.add_breakpoint(address) {
+                    true => format!("Breakpoint set at {}", address),
+                    false => format!("Breakpoint already set at {}", address),
+                },
+                Err(error) => error,
+            },
+            (Some("d" | "delete"), address) => match parse_address(address) {
+                Ok(address) => match debugger.remove_breakpoint(address) {
+                    true => format!("Breakpoint removed at {}", address),
+                    false => format!("No breakpoint at {}", address),
+                },
+                Err(error) => error,
+            },
+            (Some("r" | "registers"), _) => format_state(debugger.vm()),
+            (Some("l" | "list"), _) => format_listing(&debugger, program),
+            (Some("t" | "trace"), _) => {
+                let entries: Vec<String> = debugger
+                    .trace()
+                    .iter()
+                    .map(|entry| entry.to_string())
+                    .collect();
+                entries.join("\n")
+            }
+            (Some("q" | "quit"), _) => break,
+            (Some("h" | "help"), _) => HELP.to_owned(),
+            (Some(command), _) => {
+                format!("Unknown command: '{}'. Type 'help' for a list.", command)
+            }
+        };
+
+        writeln!(output, "{}", message)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_17_chronospatial_computer::debugger::*;
+    use crate::day_17_chronospatial_computer::vm::parse_program;
+
+    #[test]
+    fn step_through_program_with_breakpoints() {
+        let file_content = std::fs::read_to_string("./test_input/17_01.txt").unwrap();
+        let program = parse_program(&file_content).unwrap();
+        let mut debugger = Debugger::new(&program);
+        debugger.add_breakpoint(4);
+
+        assert_eq!(debugger.continue_execution(), StopReason::Breakpoint(4));
+        assert_eq!(debugger.vm().output(), [4]);
+        assert_eq!(debugger.trace().len(), 2);
+        assert_eq!(debugger.trace()[1].to_string(), " 2: out a  A=364 B=0 C=0");
+
+        assert!(debugger.remove_breakpoint(4));
+        assert_eq!(debugger.continue_execution(), StopReason::Halted);
+        assert_eq!(debugger.vm().output(), [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+
+        let mut output: Vec<u8> = Vec::new();
+        run_session(&program, &[2], "c\nstep 2\nl\nq\n".as_bytes(), &mut output).unwrap();
+        let expected: Vec<&str> = vec![
+            "ip=0 A=729 B=0 C=0 next: adv 1 output: ",
+            "Breakpoint at 2",
+            "ip=2 A=364 B=0 C=0 next: out a output: ",
+            " 2: out a  A=364 B=0 C=0",
+            " 4: jnz 0  A=364 B=0 C=0",
+            "ip=0 A=364 B=0 C=0 next: adv 1 output: 4",
+            ">  0: adv 1",
+            " * 2: out a",
+            "   4: jnz 0",
+        ];
+        assert_eq!(
+            String::from_utf8(output)
+                .unwrap()
+                .lines()
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+}
@@ -1,2 +1,72 @@
+use crate::cli::{VmAction, VmOptions};
+use crate::day_17_chronospatial_computer::vm::{Program, Register, Vm, VmError};
+use crate::solution::Registry;
+
+pub(crate) mod debugger;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod solver;
+pub(crate) mod vm;
+
+pub(crate) fn register(registry: &mut Registry) {
+    registry.register(part_1::Part1);
+    registry.register(part_2::Part2);
+}
+
+fn run_with_width<R: Register>(program: &Program, max_steps: usize) -> Result<Vec<u8>, VmError> {
+    let registers: [R; 3] = vm::convert_registers(program.registers)?;
+    Vm::new(registers, &program.code)
+        .with_step_limit(max_steps)
+        .run()
+}
+
+pub(crate) fn run_vm_command(options: &VmOptions, file_content: &str) -> bool {
+    let program = match options.action {
+        VmAction::Assemble => vm::assemble(file_content),
+        VmAction::Disassemble | VmAction::Debug | VmAction::Run => vm::parse_program(file_content),
+    };
+
+    let program: Program = match program {
+        Ok(program) => program,
+        Err(error) => {
+            eprintln!("{}", error);
+            return false;
+        }
+    };
+
+    match options.action {
+        VmAction::Disassemble => println!("{}", vm::disassemble(&program)),
+        VmAction::Assemble => println!("{}", vm::format_program(&program)),
+        VmAction::Debug => {
+            let stdin = std::io::stdin().lock();
+
+            if let Err(error) =
+                debugger::run_session(&program, &options.breakpoints, stdin, std::io::stdout())
+            {
+                eprintln!("{}", error);
+                return false;
+            }
+        }
+        VmAction::Run => {
+            let output = match options.width {
+                32 => run_with_width::<u32>(&program, options.max_steps),
+                128 => run_with_width::<u128>(&program, options.max_steps),
+                _ => run_with_width::<u64>(&program, options.max_steps),
+            };
+
+            match output {
+                Ok(output) => {
+                    let output: Vec<String> =
+                        output.iter().map(|value| value.to_string()).collect();
+                    println!("{}", output.join(","));
+                }
+                Err(error) => {
+                    eprintln!("{}", error);
+                    return false;
+                }
+            }
+        }
+    }
+
+    true
+}
@@ -0,0 +1,258 @@
+use crate::day_17_chronospatial_computer::vm::{Instruction, Opcode, Program, Vm};
+use std::fmt;
+
+const REGISTER_NAMES: [char; 3] = ['A', 'B', 'C'];
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum SolverError {
+    NotALoop,
+    InnerJump {
+        address: usize,
+    },
+    ReservedOperand {
+        address: usize,
+        instruction: Instruction,
+    },
+    UnsupportedShift {
+        shifts: usize,
+    },
+    UnsupportedOutput {
+        outputs: usize,
+    },
+    CarriedRegister {
+        address: usize,
+        instruction: Instruction,
+        register: char,
+    },
+    TargetTooLong {
+        bits: usize,
+    },
+    NoSolution,
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolverError::NotALoop => write!(
+                f,
+                "the program does not end with 'jnz 0', so it is not a single loop over register A"
+            ),
+            SolverError::InnerJump { address } => write!(
+                f,
+                "'jnz' at address {} jumps inside the loop, only a final 'jnz 0' is supported",
+                address
+            ),
+            SolverError::ReservedOperand {
+                address,
+                instruction,
+            } => write!(
+                f,
+                "'{}' at address {} uses the reserved combo operand 7",
+                instruction, address
+            ),
+            SolverError::UnsupportedShift { shifts } => write!(
+                f,
+                "expected exactly one 'adv' shifting register A by a constant 1-3, found {}",
+                shifts
+            ),
+            SolverError::UnsupportedOutput { outputs } => write!(
+                f,
+                "expected exactly one 'out' in the loop, found {}",
+                outputs
+            ),
+            SolverError::CarriedRegister {
+                address,
+                instruction,
+                register,
+            } => write!(
+                f,
+                "'{}' at address {} reads register {} before the loop writes it, \
+                so each output does not depend on register A alone",
+                instruction, address, register
+            ),
+            SolverError::TargetTooLong { bits } => write!(
+                f,
+                "the target output needs {} bits of register A, more than fit in 64",
+                bits
+            ),
+            SolverError::NoSolution => {
+                write!(f, "no value of register A produces the target output")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SolverError {}
+
+fn combo_register(operand: u8) -> Option<usize> {
+    match operand {
+        4..=6 => Some(operand as usize - 4),
+        _ => None,
+    }
+}
+
+fn get_read_registers(instruction: Instruction) -> Vec<usize> {
+    let combo: Option<usize> = combo_register(instruction.operand);
+
+    match instruction.opcode {
+        Opcode::Adv | Opcode::Bdv | Opcode::Cdv => [Some(0), combo].into_iter().flatten().collect(),
+        Opcode::Bst | Opcode::Out => combo.into_iter().collect(),
+        Opcode::Bxl => vec![1],
+        Opcode::Bxc => vec![1, 2],
+        Opcode::Jnz => vec![0],
+    }
+}
+
+fn get_written_register(opcode: Opcode) -> Option<usize> {
+    match opcode {
+        Opcode::Adv => Some(0),
+        Opcode::Bxl | Opcode::Bst | Opcode::Bxc | Opcode::Bdv => Some(1),
+        Opcode::Cdv => Some(2),
+        Opcode::Jnz | Opcode::Out => None,
+    }
+}
+
+fn find_shift_per_iteration(code: &[u8]) -> Result<u32, SolverError> {
+    let instructions: Vec<(usize, Instruction)> = (0..code.len())
+        .step_by(2)
+        .map(|address| Instruction::decode(code, address).map(|instruction| (address, instruction)))
+        .collect::<Option<_>>()
+        .ok_or(SolverError::NotALoop)?;
+
+    let Some((&(_, last), body)) = instructions.split_last() else {
+        return Err(SolverError::NotALoop);
+    };
+
+    if last.opcode != Opcode::Jnz || last.operand != 0 {
+        return Err(SolverError::NotALoop);
+    }
+
+    let mut written: [bool; 3] = [false; 3];
+    let mut shifts: Vec<u8> = Vec::new();
+    let mut outputs: usize = 0;
+
+    for &(address, instruction) in body {
+        match instruction.opcode {
+            Opcode::Jnz => return Err(SolverError::InnerJump { address }),
+            Opcode::Adv => shifts.push(instruction.operand),
+            Opcode::Out => outputs += 1,
+            _ => {}
+        }
+
+        if instruction.operand == 7 && !matches!(instruction.opcode, Opcode::Bxl | Opcode::Bxc) {
+            return Err(SolverError::ReservedOperand {
+                address,
+                instruction,
+            });
+        }
+
+        if let Some(register) = get_read_registers(instruction)
+            .into_iter()
+            .find(|&register| register != 0 && !written[register])
+        {
+            return Err(SolverError::CarriedRegister {
+                address,
+                instruction,
+                register: REGISTER_NAMES[register],
+            });
+        }
+
+        if let Some(register) = get_written_register(instruction.opcode) {
+            written[register] = true;
+        }
+    }
+
+    if outputs != 1 {
+        return Err(SolverError::UnsupportedOutput { outputs });
+    }
+
+    match shifts[..] {
+        [shift @ 1..=3] => Ok(shift as u32),
+        _ => Err(SolverError::UnsupportedShift {
+            shifts: shifts.len(),
+        }),
+    }
+}
+
+fn search_from_last_output(
+    program: &Program,
+    target: &[u8],
+    shift: u32,
+    a_register: u64,
+    matched: usize,
+) -> Option<u64> {
+    if matched == target.len() {
+        return Some(a_register);
+    }
+
+    let (_, expected_output) = target.split_at(target.len() - matched - 1);
+    let [_, b, c]: [u128; 3] = program.registers;
+
+    (0..1 << shift)
+        .map(|next_part_of_a| a_register << shift | next_part_of_a)
+        .filter(|&a_register| {
+            Vm::new([a_register.into(), b, c], &program.code)
+                .run()
+                .is_ok_and(|output| output == expected_output)
+        })
+        .find_map(|a_register| {
+            search_from_last_output(program, target, shift, a_register, matched + 1)
+        })
+}
+
+pub(crate) fn find_smallest_a_register(
+    program: &Program,
+    target: &[u8],
+) -> Result<u64, SolverError> {
+    let shift: u32 = find_shift_per_iteration(&program.code)?;
+    let bits: usize = target.len() * shift as usize;
+
+    if bits > u64::BITS as usize {
+        return Err(SolverError::TargetTooLong { bits });
+    }
+
+    search_from_last_output(program, target, shift, 0, 0).ok_or(SolverError::NoSolution)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_17_chronospatial_computer::solver::*;
+    use crate::day_17_chronospatial_computer::vm::assemble;
+
+    fn program(body: &str) -> Program {
+        assemble(&format!(
+            "Register A: 0\nRegister B: 0\nRegister C: 0\n\n{}",
+            body
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn find_register_for_any_target() {
+        let countdown = program("adv 1\nout a\njnz 0");
+        assert_eq!(find_smallest_a_register(&countdown, &[6, 3, 1, 0]), Ok(12));
+        assert_eq!(
+            find_smallest_a_register(&countdown, &[1, 1]),
+            Err(SolverError::NoSolution)
+        );
+
+        let scrambled = program("bst a\nbxl 5\ncdv b\nbxl 6\nadv 3\nbxc\nout b\njnz 0");
+        assert_eq!(find_smallest_a_register(&scrambled, &[1, 1, 1, 2]), Ok(663));
+    }
+
+    #[test]
+    fn explain_unsupported_programs() {
+        let error = find_smallest_a_register(&program("adv 3\nout a\njnz 2"), &[0]);
+        assert_eq!(error, Err(SolverError::NotALoop));
+
+        let error = find_smallest_a_register(&program("bxc\nadv 3\nout b\njnz 0"), &[0]);
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "'bxc' at address 0 reads register B before the loop writes it, \
+            so each output does not depend on register A alone"
+        );
+
+        let error = find_smallest_a_register(&program("adv 3\nadv 1\nout a\njnz 0"), &[0]);
+        assert_eq!(error, Err(SolverError::UnsupportedShift { shifts: 2 }));
+    }
+}
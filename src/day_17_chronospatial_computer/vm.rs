@@ -0,0 +1,570 @@
+use crate::parsing::{parse_value, split_at_delimiter, ParseError};
+use std::fmt;
+use std::ops::{BitXor, Rem, Shr};
+
+const REGISTER_NAMES: [char; 3] = ['A', 'B', 'C'];
+pub(crate) const DEFAULT_STEP_LIMIT: usize = 1_000_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Program {
+    pub(crate) registers: [u128; 3],
+    pub(crate) code: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    const ALL: [Opcode; 8] = [
+        Opcode::Adv,
+        Opcode::Bxl,
+        Opcode::Bst,
+        Opcode::Jnz,
+        Opcode::Bxc,
+        Opcode::Out,
+        Opcode::Bdv,
+        Opcode::Cdv,
+    ];
+
+    fn from_u8(value: u8) -> Option<Self> {
+        Opcode::ALL.get(value as usize).copied()
+    }
+
+    fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+
+    fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        Opcode::ALL
+            .into_iter()
+            .find(|opcode| opcode.mnemonic() == mnemonic)
+    }
+
+    fn has_combo_operand(self) -> bool {
+        matches!(
+            self,
+            Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Instruction {
+    pub(crate) opcode: Opcode,
+    pub(crate) operand: u8,
+}
+
+impl Instruction {
+    pub(crate) fn decode(code: &[u8], address: usize) -> Option<Self> {
+        Some(Instruction {
+            opcode: Opcode::from_u8(*code.get(address)?)?,
+            operand: *code.get(address + 1)?,
+        })
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.opcode, self.operand) {
+            (Opcode::Bxc, 0) => write!(f, "bxc"),
+            (opcode, operand @ 4..=6) if opcode.has_combo_operand() => {
+                let register: char = REGISTER_NAMES[operand as usize - 4];
+                write!(f, "{} {}", opcode.mnemonic(), register.to_ascii_lowercase())
+            }
+            (opcode, operand) => write!(f, "{} {}", opcode.mnemonic(), operand),
+        }
+    }
+}
+
+fn parse_registers<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+) -> Result<[u128; 3], ParseError> {
+    let mut registers: Vec<u128> = Vec::new();
+
+    for (line_index, line) in lines.take_while(|(_, line)| !line.is_empty()) {
+        if registers.len() == 3 {
+            return Err(ParseError::unexpected_format(
+                line_index,
+                line,
+                line,
+                "an empty line after three registers",
+            ));
+        }
+
+        let (_, number) = split_at_delimiter(line_index, line, line, ": ", "Register X: <number>")?;
+        registers.push(parse_value(
+            line_index,
+            line,
+            number,
+            "a valid u128 number",
+        )?);
+    }
+
+    registers
+        .try_into()
+        .map_err(|_| ParseError::missing_element("registers A, B and C"))
+}
+
+fn parse_three_bit_value(
+    line_index: usize,
+    line: &str,
+    token: &str,
+    expected: &'static str,
+) -> Result<u8, ParseError> {
+    match parse_value(line_index, line, token, expected)? {
+        value @ 0..=7 => Ok(value),
+        _ => Err(ParseError::invalid_value(line_index, line, token, expected)),
+    }
+}
+
+pub(crate) fn parse_program(file_content: &str) -> Result<Program, ParseError> {
+    let mut lines = file_content.lines().enumerate();
+    let registers: [u128; 3] = parse_registers(&mut lines)?;
+    let mut code: Vec<u8> = Vec::new();
+
+    for (line_index, line) in lines {
+        let (_, numbers) = split_at_delimiter(line_index, line, line, ": ", "Program: <numbers>")?;
+
+        for number in numbers.split(',') {
+            code.push(parse_three_bit_value(
+                line_index,
+                line,
+                number,
+                "a 3-bit number",
+            )?);
+        }
+    }
+
+    match code.is_empty() {
+        true => Err(ParseError::missing_element("a program")),
+        false => Ok(Program { registers, code }),
+    }
+}
+
+fn format_registers(registers: &[u128; 3]) -> String {
+    REGISTER_NAMES
+        .iter()
+        .zip(registers)
+        .map(|(name, value)| format!("Register {}: {}\n", name, value))
+        .collect()
+}
+
+pub(crate) fn format_program(program: &Program) -> String {
+    let code: Vec<String> = program.code.iter().map(|value| value.to_string()).collect();
+    format!(
+        "{}\nProgram: {}",
+        format_registers(&program.registers),
+        code.join(",")
+    )
+}
+
+pub(crate) fn disassemble(program: &Program) -> String {
+    let mut lines: Vec<String> = Vec::new();
+
+    for address in (0..program.code.len()).step_by(2) {
+        match Instruction::decode(&program.code, address) {
+            Some(instruction) => lines.push(format!("{:>2}: {}", address, instruction)),
+            None => lines.push(format!("{:>2}: .byte {}", address, program.code[address])),
+        }
+    }
+
+    format!(
+        "{}\n{}",
+        format_registers(&program.registers),
+        lines.join("\n")
+    )
+}
+
+fn parse_operand(
+    line_index: usize,
+    line: &str,
+    opcode: Opcode,
+    operand: Option<&str>,
+) -> Result<u8, ParseError> {
+    let expected: &'static str = match opcode.has_combo_operand() {
+        true => "a combo operand 0-3, a, b or c",
+        false => "a literal operand 0-7",
+    };
+
+    let Some(operand) = operand else {
+        return match opcode {
+            Opcode::Bxc => Ok(0),
+            _ => Err(ParseError::unexpected_format(
+                line_index, line, line, expected,
+            )),
+        };
+    };
+
+    match operand {
+        "a" | "b" | "c" if opcode.has_combo_operand() => Ok(4 + (operand.as_bytes()[0] - b'a')),
+        _ => parse_three_bit_value(line_index, line, operand, expected),
+    }
+}
+
+pub(crate) fn assemble(source: &str) -> Result<Program, ParseError> {
+    let mut lines = source.lines().enumerate();
+    let registers: [u128; 3] = parse_registers(&mut lines)?;
+    let mut code: Vec<u8> = Vec::new();
+
+    for (line_index, line) in lines {
+        let statement: &str = line.split(';').next().unwrap_or_default();
+        let statement: &str = match statement.split_once(':') {
+            Some((_, statement)) => statement,
+            None => statement,
+        };
+        let mut tokens = statement.split_whitespace();
+
+        let Some(mnemonic) = tokens.next() else {
+            continue;
+        };
+
+        if mnemonic == ".byte" {
+            let value: &str = tokens.next().ok_or_else(|| {
+                ParseError::unexpected_format(line_index, line, line, "'.byte <number>'")
+            })?;
+            code.push(parse_three_bit_value(
+                line_index,
+                line,
+                value,
+                "a 3-bit number",
+            )?);
+            continue;
+        }
+
+        let opcode: Opcode = Opcode::from_mnemonic(mnemonic).ok_or_else(|| {
+            ParseError::invalid_value(line_index, line, mnemonic, "an instruction mnemonic")
+        })?;
+        let operand: u8 = parse_operand(line_index, line, opcode, tokens.next())?;
+
+        if let Some(token) = tokens.next() {
+            return Err(ParseError::unexpected_format(
+                line_index,
+                line,
+                token,
+                "a single operand",
+            ));
+        }
+
+        code.extend([opcode as u8, operand]);
+    }
+
+    match code.is_empty() {
+        true => Err(ParseError::missing_element("a program")),
+        false => Ok(Program { registers, code }),
+    }
+}
+
+pub(crate) trait Register:
+    Copy
+    + PartialEq
+    + From<u8>
+    + Into<u128>
+    + TryFrom<u128>
+    + TryInto<u8>
+    + TryInto<u32>
+    + BitXor<Output = Self>
+    + Rem<Output = Self>
+    + Shr<u32, Output = Self>
+{
+    const BITS: u32;
+}
+
+impl Register for u32 {
+    const BITS: u32 = u32::BITS;
+}
+
+impl Register for u64 {
+    const BITS: u32 = u64::BITS;
+}
+
+impl Register for u128 {
+    const BITS: u32 = u128::BITS;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum VmError {
+    ReservedComboOperand {
+        address: usize,
+        instruction: Instruction,
+    },
+    ShiftOutOfRange {
+        address: usize,
+        shift: u128,
+        bits: u32,
+    },
+    StepLimitExceeded {
+        steps: usize,
+    },
+    RegisterOutOfRange {
+        register: char,
+        value: u128,
+        bits: u32,
+    },
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VmError::ReservedComboOperand {
+                address,
+                instruction,
+            } => write!(
+                f,
+                "'{}' at address {} uses the reserved combo operand 7",
+                instruction, address
+            ),
+            VmError::ShiftOutOfRange {
+                address,
+                shift,
+                bits,
+            } => write!(
+                f,
+                "shift by {} at address {} is out of range for {}-bit registers",
+                shift, address, bits
+            ),
+            VmError::StepLimitExceeded { steps } => {
+                write!(f, "program did not halt within {} steps", steps)
+            }
+            VmError::RegisterOutOfRange {
+                register,
+                value,
+                bits,
+            } => write!(
+                f,
+                "register {} value {} does not fit in {} bits",
+                register, value, bits
+            ),
+        }
+    }
+}
+
+impl std::error::Error for VmError {}
+
+pub(crate) fn convert_registers<R: Register>(registers: [u128; 3]) -> Result<[R; 3], VmError> {
+    let mut converted: [R; 3] = [R::from(0); 3];
+
+    for (index, &value) in registers.iter().enumerate() {
+        converted[index] = R::try_from(value).map_err(|_| VmError::RegisterOutOfRange {
+            register: REGISTER_NAMES[index],
+            value,
+            bits: R::BITS,
+        })?;
+    }
+
+    Ok(converted)
+}
+
+fn get_three_lowest_bits<R: Register>(value: R) -> u8 {
+    (value % R::from(8))
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("Value modulo 8 should fit in u8"))
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Vm<'a, R: Register = u64> {
+    code: &'a [u8],
+    registers: [R; 3],
+    instruction_pointer: usize,
+    output: Vec<u8>,
+    steps: usize,
+    step_limit: usize,
+}
+
+impl<'a, R: Register> Vm<'a, R> {
+    pub(crate) fn new(registers: [R; 3], code: &'a [u8]) -> Self {
+        Vm {
+            code,
+            registers,
+            instruction_pointer: 0,
+            output: Vec::new(),
+            steps: 0,
+            step_limit: DEFAULT_STEP_LIMIT,
+        }
+    }
+
+    pub(crate) fn with_step_limit(mut self, step_limit: usize) -> Self {
+        self.step_limit = step_limit;
+        self
+    }
+
+    pub(crate) fn registers(&self) -> [R; 3] {
+        self.registers
+    }
+
+    pub(crate) fn instruction_pointer(&self) -> usize {
+        self.instruction_pointer
+    }
+
+    pub(crate) fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    pub(crate) fn current_instruction(&self) -> Option<Instruction> {
+        Instruction::decode(self.code, self.instruction_pointer)
+    }
+
+    fn get_combo_operand(&self, instruction: Instruction) -> Result<R, VmError> {
+        let [a, b, c]: [R; 3] = self.registers;
+
+        match instruction.operand {
+            0..=3 => Ok(R::from(instruction.operand)),
+            4 => Ok(a),
+            5 => Ok(b),
+            6 => Ok(c),
+            _ => Err(VmError::ReservedComboOperand {
+                address: self.instruction_pointer,
+                instruction,
+            }),
+        }
+    }
+
+    fn shift_right(&self, value: R, instruction: Instruction) -> Result<R, VmError> {
+        let shift: R = self.get_combo_operand(instruction)?;
+
+        match shift.try_into() {
+            Ok(shift) if shift < R::BITS => Ok(value >> shift),
+            _ => Err(VmError::ShiftOutOfRange {
+                address: self.instruction_pointer,
+                shift: shift.into(),
+                bits: R::BITS,
+            }),
+        }
+    }
+
+    pub(crate) fn step(&mut self) -> Result<Option<Instruction>, VmError> {
+        let Some(instruction) = self.current_instruction() else {
+            return Ok(None);
+        };
+
+        if self.steps == self.step_limit {
+            return Err(VmError::StepLimitExceeded { steps: self.steps });
+        }
+
+        let operand: u8 = instruction.operand;
+        let [mut a, mut b, mut c]: [R; 3] = self.registers;
+        let mut next_instruction_pointer: usize = self.instruction_pointer + 2;
+
+        match instruction.opcode {
+            Opcode::Adv => a = self.shift_right(a, instruction)?,
+            Opcode::Bxl => b = b ^ R::from(operand),
+            Opcode::Bst => b = R::from(get_three_lowest_bits(self.get_combo_operand(instruction)?)),
+            Opcode::Jnz => {
+                if a != R::from(0) {
+                    next_instruction_pointer = operand as usize;
+                }
+            }
+            Opcode::Bxc => b = b ^ c,
+            Opcode::Out => {
+                let value: R = self.get_combo_operand(instruction)?;
+                self.output.push(get_three_lowest_bits(value));
+            }
+            Opcode::Bdv => b = self.shift_right(a, instruction)?,
+            Opcode::Cdv => c = self.shift_right(a, instruction)?,
+        }
+
+        self.registers = [a, b, c];
+        self.instruction_pointer = next_instruction_pointer;
+        self.steps += 1;
+        Ok(Some(instruction))
+    }
+
+    pub(crate) fn run(mut self) -> Result<Vec<u8>, VmError> {
+        while self.step()?.is_some() {}
+        Ok(self.output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_17_chronospatial_computer::vm::*;
+
+    #[test]
+    fn disassemble_and_assemble_program() {
+        let file_content = std::fs::read_to_string("./test_input/17_01.txt").unwrap();
+        let program = parse_program(&file_content).unwrap();
+        let source = disassemble(&program);
+        assert_eq!(
+            source,
+            "Register A: 729\nRegister B: 0\nRegister C: 0\n\n 0: adv 1\n 2: out a\n 4: jnz 0"
+        );
+        assert_eq!(assemble(&source), Ok(program.clone()));
+        assert_eq!(format_program(&program), file_content.trim_end());
+
+        let error = assemble("Register A: 1\nRegister B: 0\nRegister C: 0\n\nbxl a");
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "Line 5, column 5: invalid value 'a', expected a literal operand 0-7"
+        );
+    }
+
+    #[test]
+    fn reject_invalid_data() {
+        let error = parse_program("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,9");
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "Line 5, column 12: invalid value '9', expected a 3-bit number"
+        );
+
+        let error = parse_program("Register A: 729\n\nProgram: 0,1");
+        assert_eq!(
+            error,
+            Err(ParseError::missing_element("registers A, B and C"))
+        );
+    }
+
+    #[test]
+    fn report_vm_errors() {
+        let program = assemble("Register A: 40\nRegister B: 0\nRegister C: 0\n\nbdv a").unwrap();
+        assert_eq!(Vm::<u64>::new([40, 0, 0], &program.code).run(), Ok(vec![]));
+        assert_eq!(Vm::<u128>::new([40, 0, 0], &program.code).run(), Ok(vec![]));
+        assert_eq!(
+            Vm::<u32>::new([40, 0, 0], &program.code).run(),
+            Err(VmError::ShiftOutOfRange {
+                address: 0,
+                shift: 40,
+                bits: 32
+            })
+        );
+        assert_eq!(
+            convert_registers::<u64>([1 << 70, 0, 0])
+                .unwrap_err()
+                .to_string(),
+            "register A value 1180591620717411303424 does not fit in 64 bits"
+        );
+        assert_eq!(
+            convert_registers::<u32>([1 << 40, 0, 0])
+                .unwrap_err()
+                .to_string(),
+            "register A value 1099511627776 does not fit in 32 bits"
+        );
+
+        let error = Vm::<u64>::new([1, 0, 0], &[5, 7]).run().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "'out 7' at address 0 uses the reserved combo operand 7"
+        );
+
+        let endless_loop = Vm::<u64>::new([1, 0, 0], &[3, 0])
+            .with_step_limit(100)
+            .run();
+        assert_eq!(endless_loop, Err(VmError::StepLimitExceeded { steps: 100 }));
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum MemoryError {
+    ByteOutsideMemory { position: [u8; 2], size: usize },
+    UnknownFallenBytes { size: usize },
+}
+
+impl fmt::Display for MemoryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MemoryError::ByteOutsideMemory {
+                position: [x, y],
+                size,
+            } => write!(
+                f,
+                "byte at {},{} is outside the {}x{} memory space",
+                x, y, size, size
+            ),
+            MemoryError::UnknownFallenBytes { size } => write!(
+                f,
+                "no known number of fallen bytes for the {}x{} memory space, pass --fallen-bytes",
+                size, size
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MemoryError {}
+
+pub(crate) fn find_memory_size(
+    positions: &[[u8; 2]],
+    configured_size: Option<usize>,
+) -> Result<usize, MemoryError> {
+    let size: usize = configured_size.unwrap_or_else(|| {
+        positions
+            .iter()
+            .flat_map(|&[x, y]| [x, y])
+            .map(|coordinate| coordinate as usize + 1)
+            .max()
+            .unwrap_or(1)
+    });
+
+    match positions
+        .iter()
+        .find(|&&[x, y]| x as usize >= size || y as usize >= size)
+    {
+        Some(&position) => Err(MemoryError::ByteOutsideMemory { position, size }),
+        None => Ok(size),
+    }
+}
+
+pub(crate) fn find_fallen_bytes(
+    size: usize,
+    configured_fallen_bytes: Option<usize>,
+) -> Result<usize, MemoryError> {
+    match (configured_fallen_bytes, size) {
+        (Some(fallen_bytes), _) => Ok(fallen_bytes),
+        (None, 7) => Ok(12),
+        (None, 71) => Ok(1024),
+        (None, _) => Err(MemoryError::UnknownFallenBytes { size }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_18_ram_run::memory::*;
+
+    #[test]
+    fn configure_or_infer_memory_space() {
+        let positions = [[5, 4], [4, 2], [0, 6]];
+        assert_eq!(find_memory_size(&positions, None), Ok(7));
+        assert_eq!(find_fallen_bytes(7, None), Ok(12));
+        assert_eq!(find_memory_size(&[[70, 3]], None), Ok(71));
+        assert_eq!(find_fallen_bytes(71, None), Ok(1024));
+        assert_eq!(find_memory_size(&positions, Some(15)), Ok(15));
+        assert_eq!(find_fallen_bytes(15, Some(2)), Ok(2));
+
+        assert_eq!(
+            find_memory_size(&positions, Some(6))
+                .unwrap_err()
+                .to_string(),
+            "byte at 0,6 is outside the 6x6 memory space"
+        );
+        assert_eq!(
+            find_fallen_bytes(91, None).unwrap_err().to_string(),
+            "no known number of fallen bytes for the 91x91 memory space, pass --fallen-bytes"
+        );
+    }
+}
@@ -1,2 +1,18 @@
+use crate::cli::PuzzleOptions;
+use crate::solution::Registry;
+
+pub(crate) mod memory;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod solver;
+
+pub(crate) fn register(registry: &mut Registry, options: &PuzzleOptions) {
+    registry.register(part_1::Part1 {
+        memory_size: options.memory_size,
+        fallen_bytes: options.fallen_bytes,
+    });
+    registry.register(part_2::Part2 {
+        memory_size: options.memory_size,
+        solver: options.byte_solver,
+    });
+}
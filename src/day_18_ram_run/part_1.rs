@@ -1,78 +1,107 @@
-use std::collections::{HashSet, VecDeque};
-
-fn process_data(file_path: &str) -> Vec<[u8; 2]> {
-    let file_content = std::fs::read_to_string(file_path).expect("Failed to open file");
+use crate::day_18_ram_run::memory::{find_fallen_bytes, find_memory_size, MemoryError};
+use crate::grid::{Grid, Point};
+use crate::parsing::{parse_value, split_at_delimiter, ParseError};
+use crate::render::{Renderer, Style, Tile};
+use crate::search::{self, SearchResult};
+use crate::solution::{Answer, Solution};
+use std::io;
 
+fn process_data(file_content: &str) -> Result<Vec<[u8; 2]>, ParseError> {
     file_content
         .lines()
-        .map(|line| {
-            line.split(',')
-                .map(|num| num.parse().expect("Should be a valid u8 number"))
-                .collect::<Vec<_>>()
-                .try_into()
-                .expect("Should contain two u8 values")
+        .enumerate()
+        .map(|(line_index, line)| {
+            let (x, y) = split_at_delimiter(line_index, line, line, ",", "X,Y coordinates")?;
+            Ok([
+                parse_value(line_index, line, x, "a valid u8 number")?,
+                parse_value(line_index, line, y, "a valid u8 number")?,
+            ])
         })
         .collect()
 }
 
-fn build_memory_maze<const N: usize>(positions: &[[u8; 2]], n: usize) -> [[bool; N]; N] {
-    let mut maze: [[bool; N]; N] = [[false; N]; N];
+fn build_memory_maze(positions: &[[u8; 2]], size: usize, n: usize) -> Grid<bool> {
+    let mut maze: Grid<bool> = Grid::filled(size, size, false);
 
     for &[x, y] in positions.iter().take(n) {
-        maze[y as usize][x as usize] = true;
+        maze[Point::new(x as i32, y as i32)] = true;
     }
 
     maze
 }
 
-fn get_valid_neighbours<const N: usize>(
-    maze: &[[bool; N]; N],
-    [x, y]: [usize; 2],
-) -> Vec<[usize; 2]> {
-    [
-        [x + 1, y],
-        [x, y + 1],
-        [x.wrapping_sub(1), y],
-        [x, y.wrapping_sub(1)],
-    ]
-    .into_iter()
-    .filter_map(|[nx, ny]| {
-        maze.get(ny)
-            .and_then(|row| row.get(nx))
-            .filter(|&&is_obstacle| !is_obstacle)
-            .map(|_| [nx, ny])
+fn search_memory_maze(maze: &Grid<bool>, start: Point, end: Point) -> SearchResult<Point> {
+    let get_next_steps = |point: Point| {
+        maze.neighbours_4(point)
+            .filter(|&next| !maze[next])
+            .map(|next| (next, 1))
+    };
+
+    search::a_star(start, end, get_next_steps, |point| {
+        point.manhattan_distance(end)
     })
-    .collect()
 }
 
-fn find_shortest_path<const N: usize>(
-    maze: &[[bool; N]; N],
-    start: [usize; 2],
-    end: [usize; 2],
-) -> u32 {
-    let mut queue: VecDeque<([usize; 2], u32)> = VecDeque::from([(start, 0)]);
-    let mut visited: HashSet<[usize; 2]> = HashSet::from([start]);
-
-    while let Some(([x, y], step_count)) = queue.pop_front() {
-        if [x, y] == end {
-            return step_count;
-        }
+fn find_shortest_path(maze: &Grid<bool>, start: Point, end: Point) -> Option<u32> {
+    search_memory_maze(maze, start, end).distance(&end)
+}
 
-        for [nx, ny] in get_valid_neighbours(maze, [x, y]) {
-            if visited.insert([nx, ny]) {
-                queue.push_back(([nx, ny], step_count + 1));
-            }
-        }
-    }
+fn build_frame(maze: &Grid<bool>) -> Grid<Tile> {
+    maze.map(|&corrupted| match corrupted {
+        true => Tile::new('#', Style::Wall),
+        false => Tile::new('.', Style::Plain),
+    })
+}
 
-    0
+pub(crate) struct Part1 {
+    pub(crate) memory_size: Option<usize>,
+    pub(crate) fallen_bytes: Option<usize>,
+}
+
+impl Part1 {
+    fn find_memory_space(&self, positions: &[[u8; 2]]) -> Result<[usize; 2], MemoryError> {
+        let size: usize = find_memory_size(positions, self.memory_size)?;
+        Ok([size, find_fallen_bytes(size, self.fallen_bytes)?])
+    }
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let positions: Vec<[u8; 2]> = process_data("./input/18.txt");
-    let maze: [[bool; 71]; 71] = build_memory_maze::<71>(&positions, 1024);
-    let result: u32 = find_shortest_path(&maze, [0, 0], [70, 70]);
-    Ok(format!("Day 18 RAM Run (Part 1): {}.", result))
+impl Solution for Part1 {
+    type Parsed = Vec<[u8; 2]>;
+
+    const DAY: u8 = 18;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "RAM Run";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, positions: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let [size, fallen_bytes]: [usize; 2] = self.find_memory_space(positions)?;
+        let maze: Grid<bool> = build_memory_maze(positions, size, fallen_bytes);
+        let end: Point = Point::new(maze.width() as i32 - 1, maze.height() as i32 - 1);
+        let result: u32 = find_shortest_path(&maze, Point::new(0, 0), end)
+            .ok_or("The exit cannot be reached from the start")?;
+        Ok(Answer::from(result))
+    }
+
+    fn visualize(&self, positions: &Self::Parsed, renderer: &mut Renderer) -> io::Result<()> {
+        let Ok([size, fallen_bytes]) = self.find_memory_space(positions) else {
+            return Ok(());
+        };
+        let maze: Grid<bool> = build_memory_maze(positions, size, fallen_bytes);
+        let end: Point = Point::new(maze.width() as i32 - 1, maze.height() as i32 - 1);
+        let mut frame: Grid<Tile> = build_frame(&maze);
+
+        for position in search_memory_maze(&maze, Point::new(0, 0), end)
+            .path_to(end)
+            .unwrap_or_default()
+        {
+            frame[position] = Tile::new('O', Style::Path);
+        }
+
+        renderer.render(&frame)
+    }
 }
 
 #[cfg(test)]
@@ -81,9 +110,14 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let positions = process_data("./test_input/18.txt");
-        let maze = build_memory_maze::<7>(&positions, 12);
-        let result = find_shortest_path(&maze, [0, 0], [6, 6]);
-        assert_eq!(result, 22);
+        let file_content = std::fs::read_to_string("./test_input/18.txt").unwrap();
+        let positions = process_data(&file_content).unwrap();
+        let maze = build_memory_maze(&positions, 7, 12);
+        let result = find_shortest_path(&maze, Point::new(0, 0), Point::new(6, 6));
+        assert_eq!(result, Some(22));
+
+        let maze = build_memory_maze(&positions, 7, positions.len());
+        let result = find_shortest_path(&maze, Point::new(0, 0), Point::new(6, 6));
+        assert_eq!(result, None);
     }
 }
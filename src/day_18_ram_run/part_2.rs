@@ -1,83 +1,55 @@
-use std::collections::{HashSet, VecDeque};
-
-fn process_data(file_path: &str) -> Vec<[u8; 2]> {
-    let file_content = std::fs::read_to_string(file_path).expect("Failed to open file");
-
+use crate::day_18_ram_run::memory::find_memory_size;
+use crate::day_18_ram_run::solver::{find_index_with_union_find, BlockingByteSolver};
+use crate::grid::{Grid, Point};
+use crate::parsing::{parse_value, split_at_delimiter, ParseError};
+use crate::render::{Renderer, Style, Tile};
+use crate::search;
+use crate::solution::{Answer, Solution};
+use std::io;
+
+fn process_data(file_content: &str) -> Result<Vec<[u8; 2]>, ParseError> {
     file_content
         .lines()
-        .map(|line| {
-            line.split(',')
-                .map(|num| num.parse().expect("Should be a valid u8 number"))
-                .collect::<Vec<_>>()
-                .try_into()
-                .expect("Should contain two u8 values")
+        .enumerate()
+        .map(|(line_index, line)| {
+            let (x, y) = split_at_delimiter(line_index, line, line, ",", "X,Y coordinates")?;
+            Ok([
+                parse_value(line_index, line, x, "a valid u8 number")?,
+                parse_value(line_index, line, y, "a valid u8 number")?,
+            ])
         })
         .collect()
 }
 
-fn build_memory_maze<const N: usize>(positions: &[[u8; 2]], n: usize) -> [[bool; N]; N] {
-    let mut maze: [[bool; N]; N] = [[false; N]; N];
+fn build_memory_maze(positions: &[[u8; 2]], size: usize, n: usize) -> Grid<bool> {
+    let mut maze: Grid<bool> = Grid::filled(size, size, false);
 
     for &[x, y] in positions.iter().take(n) {
-        maze[y as usize][x as usize] = true;
+        maze[Point::new(x as i32, y as i32)] = true;
     }
 
     maze
 }
 
-fn get_valid_neighbours<const N: usize>(
-    maze: &[[bool; N]; N],
-    [x, y]: [usize; 2],
-) -> Vec<[usize; 2]> {
-    [
-        [x + 1, y],
-        [x, y + 1],
-        [x.wrapping_sub(1), y],
-        [x, y.wrapping_sub(1)],
-    ]
-    .into_iter()
-    .filter_map(|[nx, ny]| {
-        maze.get(ny)
-            .and_then(|row| row.get(nx))
-            .filter(|&&is_obstacle| !is_obstacle)
-            .map(|_| [nx, ny])
+fn has_viable_path(maze: &Grid<bool>, start: Point, end: Point) -> bool {
+    search::bfs(start, |point| {
+        maze.neighbours_4(point).filter(|&next| !maze[next])
     })
-    .collect()
-}
-
-fn has_viable_path<const N: usize>(
-    maze: &[[bool; N]; N],
-    start: [usize; 2],
-    end: [usize; 2],
-) -> bool {
-    let mut queue: VecDeque<[usize; 2]> = VecDeque::from([start]);
-    let mut visited: HashSet<[usize; 2]> = HashSet::from([start]);
-
-    while let Some([x, y]) = queue.pop_front() {
-        if [x, y] == end {
-            return true;
-        }
-
-        for [nx, ny] in get_valid_neighbours(maze, [x, y]) {
-            if visited.insert([nx, ny]) {
-                queue.push_back([nx, ny]);
-            }
-        }
-    }
-
-    false
+    .distance(&end)
+    .is_some()
 }
 
-fn binary_search_for_obstacle_that_breaks_path<const N: usize>(
+fn find_index_with_binary_search(
     positions: &[[u8; 2]],
-    start: [usize; 2],
-    end: [usize; 2],
-) -> String {
-    let [mut low, mut high]: [usize; 2] = [0, positions.len() - 1];
+    size: usize,
+    start: Point,
+    end: Point,
+) -> Option<usize> {
+    let [mut low, mut high]: [usize; 2] = [0, positions.len()];
 
     while low < high {
         let mid = (low + high) / 2;
-        let maze = build_memory_maze::<N>(positions, mid + 1);
+        let maze = build_memory_maze(positions, size, mid + 1);
 
         match has_viable_path(&maze, start, end) {
             true => low = mid + 1,
@@ -85,15 +57,84 @@ fn binary_search_for_obstacle_that_breaks_path<const N: usize>(
         }
     }
 
-    let [x, y] = positions[low];
+    (low < positions.len()).then_some(low)
+}
+
+fn find_index_of_obstacle_that_breaks_path(
+    positions: &[[u8; 2]],
+    size: usize,
+    start: Point,
+    end: Point,
+    solver: BlockingByteSolver,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    let index: Option<usize> = match solver {
+        BlockingByteSolver::BinarySearch => {
+            find_index_with_binary_search(positions, size, start, end)
+        }
+        BlockingByteSolver::UnionFind => find_index_with_union_find(positions, size, start, end),
+    };
+    Ok(index.ok_or("No falling byte blocks the path to the exit")?)
+}
+
+fn format_position([x, y]: [u8; 2]) -> String {
     format!("{},{}", x, y)
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let positions: Vec<[u8; 2]> = process_data("./input/18.txt");
-    let result: String =
-        binary_search_for_obstacle_that_breaks_path::<71>(&positions, [0, 0], [70, 70]);
-    Ok(format!("Day 18 RAM Run (Part 2): {}.", result))
+fn build_frame(maze: &Grid<bool>) -> Grid<Tile> {
+    maze.map(|&corrupted| match corrupted {
+        true => Tile::new('#', Style::Wall),
+        false => Tile::new('.', Style::Plain),
+    })
+}
+
+pub(crate) struct Part2 {
+    pub(crate) memory_size: Option<usize>,
+    pub(crate) solver: BlockingByteSolver,
+}
+
+impl Solution for Part2 {
+    type Parsed = Vec<[u8; 2]>;
+
+    const DAY: u8 = 18;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "RAM Run";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, positions: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let size: usize = find_memory_size(positions, self.memory_size)?;
+        let end: Point = Point::new(size as i32 - 1, size as i32 - 1);
+        let index: usize = find_index_of_obstacle_that_breaks_path(
+            positions,
+            size,
+            Point::new(0, 0),
+            end,
+            self.solver,
+        )?;
+        Ok(Answer::from(format_position(positions[index])))
+    }
+
+    fn visualize(&self, positions: &Self::Parsed, renderer: &mut Renderer) -> io::Result<()> {
+        let Ok(size) = find_memory_size(positions, self.memory_size) else {
+            return Ok(());
+        };
+        let end: Point = Point::new(size as i32 - 1, size as i32 - 1);
+        let Ok(index) = find_index_of_obstacle_that_breaks_path(
+            positions,
+            size,
+            Point::new(0, 0),
+            end,
+            self.solver,
+        ) else {
+            return Ok(());
+        };
+        let [x, y] = positions[index];
+        let mut frame: Grid<Tile> = build_frame(&build_memory_maze(positions, size, index + 1));
+        frame[Point::new(x as i32, y as i32)] = Tile::new('X', Style::Actor);
+        renderer.render(&frame)
+    }
 }
 
 #[cfg(test)]
@@ -102,8 +143,22 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let positions = process_data("./test_input/18.txt");
-        let result = binary_search_for_obstacle_that_breaks_path::<7>(&positions, [0, 0], [6, 6]);
-        assert_eq!(result, "6,1");
+        let file_content = std::fs::read_to_string("./test_input/18.txt").unwrap();
+        let positions = process_data(&file_content).unwrap();
+
+        for solver in [
+            BlockingByteSolver::BinarySearch,
+            BlockingByteSolver::UnionFind,
+        ] {
+            let index = find_index_of_obstacle_that_breaks_path(
+                &positions,
+                7,
+                Point::new(0, 0),
+                Point::new(6, 6),
+                solver,
+            )
+            .unwrap();
+            assert_eq!(format_position(positions[index]), "6,1");
+        }
     }
 }
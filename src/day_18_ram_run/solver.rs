@@ -0,0 +1,159 @@
+use crate::grid::{Grid, Point};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) enum BlockingByteSolver {
+    BinarySearch,
+    #[default]
+    UnionFind,
+}
+
+pub(crate) fn parse_solver(text: &str) -> Option<BlockingByteSolver> {
+    match text.trim() {
+        "union-find" => Some(BlockingByteSolver::UnionFind),
+        "binary-search" => Some(BlockingByteSolver::BinarySearch),
+        _ => None,
+    }
+}
+
+struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(count: usize) -> Self {
+        UnionFind {
+            parents: (0..count).collect(),
+            sizes: vec![1; count],
+        }
+    }
+
+    fn find(&mut self, element: usize) -> usize {
+        let mut root: usize = element;
+
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current: usize = element;
+
+        while self.parents[current] != root {
+            let next: usize = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    fn union(&mut self, first: usize, second: usize) {
+        let [mut first_root, mut second_root]: [usize; 2] = [self.find(first), self.find(second)];
+
+        if first_root == second_root {
+            return;
+        }
+
+        if self.sizes[first_root] < self.sizes[second_root] {
+            std::mem::swap(&mut first_root, &mut second_root);
+        }
+
+        self.parents[second_root] = first_root;
+        self.sizes[first_root] += self.sizes[second_root];
+    }
+
+    fn connected(&mut self, first: usize, second: usize) -> bool {
+        self.find(first) == self.find(second)
+    }
+}
+
+fn cell_index(maze: &Grid<Option<usize>>, point: Point) -> usize {
+    point.y as usize * maze.width() + point.x as usize
+}
+
+fn connect_to_free_neighbours(maze: &Grid<Option<usize>>, cells: &mut UnionFind, point: Point) {
+    for neighbour in maze.neighbours_4(point) {
+        if maze[neighbour].is_none() {
+            cells.union(cell_index(maze, point), cell_index(maze, neighbour));
+        }
+    }
+}
+
+pub(crate) fn find_index_with_union_find(
+    positions: &[[u8; 2]],
+    size: usize,
+    start: Point,
+    end: Point,
+) -> Option<usize> {
+    let mut first_fallen: Grid<Option<usize>> = Grid::filled(size, size, None);
+
+    for (index, &[x, y]) in positions.iter().enumerate() {
+        first_fallen[Point::new(x as i32, y as i32)].get_or_insert(index);
+    }
+
+    let mut cells: UnionFind = UnionFind::new(size * size);
+    let free_cells: Vec<Point> = first_fallen
+        .iter()
+        .filter(|(_, fallen)| fallen.is_none())
+        .map(|(point, _)| point)
+        .collect();
+
+    for point in free_cells {
+        connect_to_free_neighbours(&first_fallen, &mut cells, point);
+    }
+
+    let [start_index, end_index]: [usize; 2] = [
+        cell_index(&first_fallen, start),
+        cell_index(&first_fallen, end),
+    ];
+
+    if cells.connected(start_index, end_index) {
+        return None;
+    }
+
+    for (index, &[x, y]) in positions.iter().enumerate().rev() {
+        let point: Point = Point::new(x as i32, y as i32);
+
+        if first_fallen[point] != Some(index) {
+            continue;
+        }
+
+        first_fallen[point] = None;
+        connect_to_free_neighbours(&first_fallen, &mut cells, point);
+
+        if cells.connected(start_index, end_index) {
+            return Some(index);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_18_ram_run::solver::*;
+
+    #[test]
+    fn find_blocking_byte_with_union_find() {
+        let [start, end] = [Point::new(0, 0), Point::new(2, 2)];
+        let positions = [[1, 0], [1, 1], [1, 0], [0, 2], [1, 2], [2, 0]];
+        assert_eq!(
+            find_index_with_union_find(&positions, 3, start, end),
+            Some(3)
+        );
+        assert_eq!(
+            find_index_with_union_find(&positions[..3], 3, start, end),
+            None
+        );
+        assert_eq!(find_index_with_union_find(&[], 3, start, end), None);
+
+        assert_eq!(
+            parse_solver("union-find"),
+            Some(BlockingByteSolver::UnionFind)
+        );
+        assert_eq!(
+            parse_solver("binary-search"),
+            Some(BlockingByteSolver::BinarySearch)
+        );
+        assert_eq!(parse_solver("bfs"), None);
+    }
+}
@@ -1,19 +1,21 @@
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
 use std::collections::HashMap;
 
-fn process_data(file_path: &str) -> (Vec<String>, Vec<String>) {
-    let file_content = std::fs::read_to_string(file_path).expect("Failed to open file");
+fn process_data(file_content: &str) -> Result<(Vec<String>, Vec<String>), ParseError> {
     let mut lines = file_content.lines();
 
     let patterns: Vec<String> = lines
         .next()
-        .expect("Should contain values separated by commas")
+        .filter(|line| !line.is_empty())
+        .ok_or_else(|| ParseError::missing_element("towel patterns separated by commas"))?
         .split(", ")
         .map(String::from)
         .collect();
 
     let designs: Vec<String> = lines.skip(1).map(String::from).collect();
 
-    (patterns, designs)
+    Ok((patterns, designs))
 }
 
 fn count_possible_ways_to_complete_design<'a>(
@@ -48,10 +50,26 @@ fn count_possible_ways_to_complete_designs(designs: &[String], patterns: &[Strin
         .sum()
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let (patterns, designs): (Vec<String>, Vec<String>) = process_data("./input/19.txt");
-    let result: usize = count_possible_ways_to_complete_designs(&designs, &patterns);
-    Ok(format!("Day 19 Linen Layout (Part 2): {}.", result))
+pub(crate) struct Part2;
+
+impl Solution for Part2 {
+    type Parsed = (Vec<String>, Vec<String>);
+
+    const DAY: u8 = 19;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Linen Layout";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(
+        &self,
+        (patterns, designs): &Self::Parsed,
+    ) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: usize = count_possible_ways_to_complete_designs(designs, patterns);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -60,7 +78,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let (patterns, designs) = process_data("./test_input/19.txt");
+        let file_content = std::fs::read_to_string("./test_input/19.txt").unwrap();
+        let (patterns, designs) = process_data(&file_content).unwrap();
         let result = count_possible_ways_to_complete_designs(&designs, &patterns);
         assert_eq!(result, 16);
     }
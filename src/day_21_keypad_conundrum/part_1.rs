@@ -1,18 +1,44 @@
-use std::collections::{HashMap, VecDeque};
-
-fn process_data(file_path: &str) -> Vec<String> {
-    let file_content = std::fs::read_to_string(file_path).expect("Failed to open file");
-
-    file_content.lines().map(|line| line.to_owned()).collect()
+use crate::direction::Direction;
+use crate::grid::Point;
+use crate::parsing::{parse_value, ParseError};
+use crate::search;
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+fn process_data(file_content: &str) -> Result<Vec<(String, usize)>, ParseError> {
+    file_content
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| {
+            let digits: &str = line
+                .strip_suffix('A')
+                .filter(|digits| !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()))
+                .ok_or_else(|| {
+                    ParseError::invalid_value(
+                        line_index,
+                        line,
+                        line,
+                        "a numeric code ending with 'A'",
+                    )
+                })?;
+            let numeric_part: usize = parse_value(
+                line_index,
+                line,
+                digits,
+                "a numeric part that fits in usize",
+            )?;
+            Ok((line.to_owned(), numeric_part))
+        })
+        .collect()
 }
 
-fn get_x_y_key_positions(keypad: &[&str]) -> HashMap<char, (u8, u8)> {
-    let mut key_positions: HashMap<char, (u8, u8)> = HashMap::new();
+fn get_x_y_key_positions(keypad: &[&str]) -> HashMap<char, Point> {
+    let mut key_positions: HashMap<char, Point> = HashMap::new();
 
     for (y, row) in keypad.iter().enumerate() {
         for (x, key) in row.chars().enumerate() {
             if key != ' ' {
-                key_positions.insert(key, (x as u8, y as u8));
+                key_positions.insert(key, Point::new(x as i32, y as i32));
             }
         }
     }
@@ -20,49 +46,34 @@ fn get_x_y_key_positions(keypad: &[&str]) -> HashMap<char, (u8, u8)> {
     key_positions
 }
 
-fn get_valid_neighbours(keypad: &[&str], (x, y): (u8, u8)) -> Vec<((u8, u8), char)> {
-    [
-        ((x + 1, y), '>'),
-        ((x, y + 1), 'v'),
-        ((x.wrapping_sub(1), y), '<'),
-        ((x, y.wrapping_sub(1)), '^'),
-    ]
-    .into_iter()
-    .filter_map(|((nx, ny), direction)| {
-        keypad
-            .get(ny as usize)
-            .and_then(|row| row.chars().nth(nx as usize))
-            .filter(|&key| key != ' ')
-            .map(|_| ((nx, ny), direction))
-    })
-    .collect()
+fn get_key(keypad: &[&str], point: Point) -> Option<char> {
+    let x: usize = usize::try_from(point.x).ok()?;
+    let y: usize = usize::try_from(point.y).ok()?;
+    keypad.get(y).and_then(|row| row.chars().nth(x))
 }
 
-fn find_minimal_possible_paths(keypad: &[&str], start: (u8, u8), end: (u8, u8)) -> Vec<String> {
-    let mut possible_paths: Vec<String> = Vec::new();
-    let mut queue: VecDeque<((u8, u8), String)> = VecDeque::from([(start, String::new())]);
-    let mut minimal_path_length: u8 = u8::MAX;
-
-    while let Some(((x, y), sequence)) = queue.pop_front() {
-        if minimal_path_length < sequence.len() as u8 + 1 {
-            return possible_paths;
-        }
-
-        for ((nx, ny), direction) in get_valid_neighbours(keypad, (x, y)) {
-            let mut next_sequence = sequence.clone();
-            next_sequence.push(direction);
-
-            if (nx, ny) == end {
-                minimal_path_length = next_sequence.len() as u8;
-                next_sequence.push('A');
-                possible_paths.push(next_sequence);
-            } else {
-                queue.push_back(((nx, ny), next_sequence));
-            }
-        }
-    }
+fn get_valid_neighbours(keypad: &[&str], point: Point) -> Vec<Point> {
+    point
+        .neighbours_4()
+        .into_iter()
+        .filter(|&next| get_key(keypad, next).is_some_and(|key| key != ' '))
+        .collect()
+}
 
-    possible_paths
+fn find_minimal_possible_paths(keypad: &[&str], start: Point, end: Point) -> Vec<String> {
+    search::bfs(start, |point| get_valid_neighbours(keypad, point))
+        .paths_to(end)
+        .map(|path| {
+            path.windows(2)
+                .map(|pair| {
+                    Direction::from_delta(pair[1] - pair[0])
+                        .expect("Consecutive keys should be neighbours")
+                        .to_char()
+                })
+                .chain(['A'])
+                .collect()
+        })
+        .collect()
 }
 
 fn get_possible_sequences_between_two_keys(keypad: &[&str]) -> HashMap<(char, char), Vec<String>> {
@@ -132,7 +143,7 @@ fn create_all_possible_sequences(
     all_possible_sequences
 }
 
-fn calculate_code_complexities_sum(keypad_inputs: &[String]) -> usize {
+fn calculate_code_complexities_sum(keypad_inputs: &[(String, usize)]) -> Option<usize> {
     let numeric_keypad: [&str; 4] = ["789", "456", "123", " 0A"];
     let sequences_between_numeric_keys: HashMap<(char, char), Vec<String>> =
         get_possible_sequences_between_two_keys(&numeric_keypad);
@@ -142,7 +153,7 @@ fn calculate_code_complexities_sum(keypad_inputs: &[String]) -> usize {
 
     keypad_inputs
         .iter()
-        .map(|keypad_input| {
+        .map(|(keypad_input, numeric_part)| {
             let input_pairs: Vec<(char, char)> = create_from_to_pairs(keypad_input);
             let mut possible_sequences: Vec<String> =
                 create_all_possible_sequences(&input_pairs, &sequences_between_numeric_keys);
@@ -170,18 +181,29 @@ fn calculate_code_complexities_sum(keypad_inputs: &[String]) -> usize {
                     .collect();
             }
 
-            let numeric_part: usize = keypad_input[..keypad_input.len() - 1]
-                .parse()
-                .expect("Should contain number");
-            possible_sequences[0].len() * numeric_part
+            possible_sequences[0].len().checked_mul(*numeric_part)
         })
-        .sum()
+        .try_fold(0, |sum: usize, complexity| sum.checked_add(complexity?))
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let keypad_inputs: Vec<String> = process_data("./input/21.txt");
-    let result: usize = calculate_code_complexities_sum(&keypad_inputs);
-    Ok(format!("Day 21 Keypad Conundrum (Part 1): {}.", result))
+pub(crate) struct Part1;
+
+impl Solution for Part1 {
+    type Parsed = Vec<(String, usize)>;
+
+    const DAY: u8 = 21;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Keypad Conundrum";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, keypad_inputs: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: usize = calculate_code_complexities_sum(keypad_inputs)
+            .ok_or("The sum of code complexities does not fit in usize")?;
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -190,8 +212,17 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let keypad_inputs = process_data("./test_input/21.txt");
+        let file_content = std::fs::read_to_string("./test_input/21.txt").unwrap();
+        let keypad_inputs = process_data(&file_content).unwrap();
         let result = calculate_code_complexities_sum(&keypad_inputs);
-        assert_eq!(result, 126384);
+        assert_eq!(result, Some(126384));
+
+        assert_eq!(
+            process_data("029A\n99999999999999999999A")
+                .unwrap_err()
+                .to_string(),
+            "Line 2, column 1: invalid value '99999999999999999999', \
+            expected a numeric part that fits in usize"
+        );
     }
 }
@@ -1,9 +1,11 @@
-fn process_data(file_path: &str) -> Vec<u64> {
-    let file_content = std::fs::read_to_string(file_path).expect("Failed to open file");
+use crate::parsing::{parse_value, ParseError};
+use crate::solution::{Answer, Solution};
 
+fn process_data(file_content: &str) -> Result<Vec<u64>, ParseError> {
     file_content
         .lines()
-        .map(|line| line.parse().expect("Should be valid u64 number"))
+        .enumerate()
+        .map(|(line_index, line)| parse_value(line_index, line, line, "a valid u64 number"))
         .collect()
 }
 
@@ -19,13 +21,26 @@ fn generate_secret_number(num: u64, n: u32) -> u64 {
     secret_number
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let secret_numbers: Vec<u64> = process_data("./input/22.txt");
-    let result: u64 = secret_numbers
-        .iter()
-        .map(|&secret_number| generate_secret_number(secret_number, 2000))
-        .sum();
-    Ok(format!("Day 22 Monkey Market (Part 1): {}.", result))
+pub(crate) struct Part1;
+
+impl Solution for Part1 {
+    type Parsed = Vec<u64>;
+
+    const DAY: u8 = 22;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Monkey Market";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, secret_numbers: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: u64 = secret_numbers
+            .iter()
+            .map(|&secret_number| generate_secret_number(secret_number, 2000))
+            .sum();
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -34,7 +49,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let secret_numbers = process_data("./test_input/22_01.txt");
+        let file_content = std::fs::read_to_string("./test_input/22_01.txt").unwrap();
+        let secret_numbers = process_data(&file_content).unwrap();
         let result: u64 = secret_numbers
             .iter()
             .map(|&secret_number| generate_secret_number(secret_number, 2000))
@@ -1,11 +1,12 @@
+use crate::parsing::{parse_value, ParseError};
+use crate::solution::{Answer, Solution};
 use std::collections::{HashMap, HashSet};
 
-fn process_data(file_path: &str) -> Vec<u64> {
-    let file_content = std::fs::read_to_string(file_path).expect("Failed to open file");
-
+fn process_data(file_content: &str) -> Result<Vec<u64>, ParseError> {
     file_content
         .lines()
-        .map(|line| line.parse().expect("Should be valid u64 number"))
+        .enumerate()
+        .map(|(line_index, line)| parse_value(line_index, line, line, "a valid u64 number"))
         .collect()
 }
 
@@ -47,10 +48,24 @@ fn find_sequence_with_most_bananas(secret_numbers: &mut [u64]) -> u32 {
     *sequence_total_bananas.values().max().unwrap_or(&0)
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let mut secret_numbers: Vec<u64> = process_data("./input/22.txt");
-    let result: u32 = find_sequence_with_most_bananas(&mut secret_numbers);
-    Ok(format!("Day 22 Monkey Market (Part 2): {}.", result))
+pub(crate) struct Part2;
+
+impl Solution for Part2 {
+    type Parsed = Vec<u64>;
+
+    const DAY: u8 = 22;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "Monkey Market";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, secret_numbers: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let mut secret_numbers: Vec<u64> = secret_numbers.clone();
+        let result: u32 = find_sequence_with_most_bananas(&mut secret_numbers);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -59,7 +74,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let mut secret_numbers = process_data("./test_input/22_02.txt");
+        let file_content = std::fs::read_to_string("./test_input/22_02.txt").unwrap();
+        let mut secret_numbers = process_data(&file_content).unwrap();
         let result = find_sequence_with_most_bananas(&mut secret_numbers);
         assert_eq!(result, 23);
     }
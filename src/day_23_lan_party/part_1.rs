@@ -1,12 +1,16 @@
+use crate::parsing::{split_at_delimiter, ParseError};
+use crate::solution::{Answer, Solution};
 use std::collections::{HashMap, HashSet};
 
-fn process_data(file_path: &str) -> Vec<[String; 2]> {
-    let file_content = std::fs::read_to_string(file_path).expect("Failed to open file");
-
+fn process_data(file_content: &str) -> Result<Vec<[String; 2]>, ParseError> {
     file_content
         .lines()
-        .filter_map(|line| line.split_once('-'))
-        .map(|(a, b)| [a.to_owned(), b.to_owned()])
+        .enumerate()
+        .map(|(line_index, line)| {
+            let (a, b) =
+                split_at_delimiter(line_index, line, line, "-", "two computers joined by '-'")?;
+            Ok([a.to_owned(), b.to_owned()])
+        })
         .collect()
 }
 
@@ -54,12 +58,25 @@ fn count_sets_with_computer_name_t_(three_computer_sets: &HashSet<Vec<&str>>) ->
         .count()
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let computer_pairs: Vec<[String; 2]> = process_data("./input/23.txt");
-    let connection_map: HashMap<&str, HashSet<&str>> = create_connection_map(&computer_pairs);
-    let three_computer_sets: HashSet<Vec<&str>> = create_three_computer_sets(&connection_map);
-    let result: usize = count_sets_with_computer_name_t_(&three_computer_sets);
-    Ok(format!("Day 23 LAN Party (Part 1): {}.", result))
+pub(crate) struct Part1;
+
+impl Solution for Part1 {
+    type Parsed = Vec<[String; 2]>;
+
+    const DAY: u8 = 23;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "LAN Party";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, computer_pairs: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let connection_map: HashMap<&str, HashSet<&str>> = create_connection_map(computer_pairs);
+        let three_computer_sets: HashSet<Vec<&str>> = create_three_computer_sets(&connection_map);
+        let result: usize = count_sets_with_computer_name_t_(&three_computer_sets);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -68,7 +85,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let computer_pairs = process_data("./test_input/23.txt");
+        let file_content = std::fs::read_to_string("./test_input/23.txt").unwrap();
+        let computer_pairs = process_data(&file_content).unwrap();
         let connection_map = create_connection_map(&computer_pairs);
         let three_computer_sets = create_three_computer_sets(&connection_map);
         let result = count_sets_with_computer_name_t_(&three_computer_sets);
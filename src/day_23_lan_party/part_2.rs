@@ -1,12 +1,16 @@
+use crate::parsing::{split_at_delimiter, ParseError};
+use crate::solution::{Answer, Solution};
 use std::collections::{HashMap, HashSet};
 
-fn process_data(file_path: &str) -> Vec<[String; 2]> {
-    let file_content = std::fs::read_to_string(file_path).expect("Failed to open file");
-
+fn process_data(file_content: &str) -> Result<Vec<[String; 2]>, ParseError> {
     file_content
         .lines()
-        .filter_map(|line| line.split_once('-'))
-        .map(|(a, b)| [a.to_owned(), b.to_owned()])
+        .enumerate()
+        .map(|(line_index, line)| {
+            let (a, b) =
+                split_at_delimiter(line_index, line, line, "-", "two computers joined by '-'")?;
+            Ok([a.to_owned(), b.to_owned()])
+        })
         .collect()
 }
 
@@ -67,21 +71,34 @@ fn find_max_clique_and_create_password(cliques: &[HashSet<&str>]) -> String {
     max.join(",")
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let computer_pairs: Vec<[String; 2]> = process_data("./input/23.txt");
-    let connection_map: HashMap<&str, HashSet<&str>> = create_connection_map(&computer_pairs);
-    let mut cliques: Vec<HashSet<&str>> = Vec::new();
-
-    bron_kerbosch_algorithm(
-        &mut HashSet::new(),
-        &mut connection_map.keys().cloned().collect(),
-        &mut HashSet::new(),
-        &connection_map,
-        &mut cliques,
-    );
-
-    let result: String = find_max_clique_and_create_password(&cliques);
-    Ok(format!("Day 23 LAN Party (Part 2): {}.", result))
+pub(crate) struct Part2;
+
+impl Solution for Part2 {
+    type Parsed = Vec<[String; 2]>;
+
+    const DAY: u8 = 23;
+    const PART: u8 = 2;
+    const TITLE: &'static str = "LAN Party";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, computer_pairs: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let connection_map: HashMap<&str, HashSet<&str>> = create_connection_map(computer_pairs);
+        let mut cliques: Vec<HashSet<&str>> = Vec::new();
+
+        bron_kerbosch_algorithm(
+            &mut HashSet::new(),
+            &mut connection_map.keys().cloned().collect(),
+            &mut HashSet::new(),
+            &connection_map,
+            &mut cliques,
+        );
+
+        let result: String = find_max_clique_and_create_password(&cliques);
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -90,7 +107,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let computer_pairs = process_data("./test_input/23.txt");
+        let file_content = std::fs::read_to_string("./test_input/23.txt").unwrap();
+        let computer_pairs = process_data(&file_content).unwrap();
         let connection_map = create_connection_map(&computer_pairs);
         let mut cliques = Vec::new();
 
@@ -0,0 +1,385 @@
+use crate::day_24_crossed_wires::netlist::{Gate, Netlist, NetlistError, Operation, WireId};
+use std::fmt;
+
+const MAX_REPAIRS_PER_BIT: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AdderRole {
+    HalfAdder,
+    Sum,
+    Carry,
+}
+
+impl fmt::Display for AdderRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AdderRole::HalfAdder => write!(f, "half-adder"),
+            AdderRole::Sum => write!(f, "sum"),
+            AdderRole::Carry => write!(f, "carry"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum AdderError {
+    WidthMismatch { x: usize, y: usize, z: usize },
+    UnsupportedWidth { bits: usize },
+    Unrepairable { bit: usize, role: AdderRole },
+    IncorrectSum { x: u64, y: u64, z: u64 },
+    Netlist(NetlistError),
+}
+
+impl fmt::Display for AdderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AdderError::WidthMismatch { x, y, z } => write!(
+                f,
+                "expected n 'x' and 'y' wires and n + 1 'z' wires, found {}, {} and {}",
+                x, y, z
+            ),
+            AdderError::UnsupportedWidth { bits } => {
+                write!(
+                    f,
+                    "{}-bit adders are not supported, expected 1-63 bits",
+                    bits
+                )
+            }
+            AdderError::Unrepairable { bit, role } => write!(
+                f,
+                "bit {}: no {} gates match a ripple-carry adder, not even after swapping outputs",
+                bit, role
+            ),
+            AdderError::IncorrectSum { x, y, z } => {
+                write!(f, "the repaired circuit adds {} + {} as {}", x, y, z)
+            }
+            AdderError::Netlist(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for AdderError {}
+
+impl From<NetlistError> for AdderError {
+    fn from(error: NetlistError) -> Self {
+        AdderError::Netlist(error)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Repair {
+    pub(crate) bit: usize,
+    pub(crate) role: AdderRole,
+    pub(crate) wires: [String; 2],
+}
+
+#[derive(Debug)]
+pub(crate) struct AdderReport {
+    pub(crate) bits: usize,
+    pub(crate) repairs: Vec<Repair>,
+}
+
+impl AdderReport {
+    pub(crate) fn swapped_wires(&self) -> Vec<&str> {
+        let mut wires: Vec<&str> = self
+            .repairs
+            .iter()
+            .flat_map(|repair| repair.wires.iter().map(String::as_str))
+            .collect();
+        wires.sort_unstable();
+        wires
+    }
+}
+
+impl fmt::Display for AdderReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Ripple-carry adder with {} input bits", self.bits)?;
+
+        for repair in &self.repairs {
+            writeln!(
+                f,
+                "bit {:>2}: broken {} gate, swap outputs {} and {}",
+                repair.bit, repair.role, repair.wires[0], repair.wires[1]
+            )?;
+        }
+
+        writeln!(f, "Swapped wires: {}", self.swapped_wires().join(","))?;
+        write!(
+            f,
+            "Verified: the repaired circuit adds every tested pair of {}-bit numbers",
+            self.bits
+        )
+    }
+}
+
+enum BitFault {
+    Swap(AdderRole, WireId, WireId),
+    Unrepairable(AdderRole),
+}
+
+fn find_gate(
+    netlist: &Netlist,
+    operation: Operation,
+    input_1: WireId,
+    input_2: WireId,
+) -> Option<WireId> {
+    netlist
+        .gates()
+        .iter()
+        .find(|gate| {
+            gate.operation == operation
+                && (gate.inputs == [input_1, input_2] || gate.inputs == [input_2, input_1])
+        })
+        .map(|gate| gate.output)
+}
+
+fn find_gate_with_input(
+    netlist: &Netlist,
+    operation: Operation,
+    input: WireId,
+) -> Option<(Gate, WireId)> {
+    netlist.gates().iter().find_map(|&gate| match gate.inputs {
+        _ if gate.operation != operation => None,
+        [first, other] | [other, first] if first == input => Some((gate, other)),
+        _ => None,
+    })
+}
+
+fn check_bit(
+    netlist: &Netlist,
+    [x, y, z]: [WireId; 3],
+    carry: Option<WireId>,
+) -> Result<WireId, BitFault> {
+    let half_sum: WireId = find_gate(netlist, Operation::Xor, x, y)
+        .ok_or(BitFault::Unrepairable(AdderRole::HalfAdder))?;
+    let half_carry: WireId = find_gate(netlist, Operation::And, x, y)
+        .ok_or(BitFault::Unrepairable(AdderRole::HalfAdder))?;
+
+    let Some(carry) = carry else {
+        return match half_sum == z {
+            true => Ok(half_carry),
+            false => Err(BitFault::Swap(AdderRole::Sum, half_sum, z)),
+        };
+    };
+
+    let (sum_gate, sum_input) = match find_gate_with_input(netlist, Operation::Xor, carry) {
+        Some(found) => found,
+        None => {
+            return Err(
+                match find_gate_with_input(netlist, Operation::Xor, half_sum) {
+                    Some((_, other)) => BitFault::Swap(AdderRole::Carry, carry, other),
+                    None => BitFault::Unrepairable(AdderRole::Sum),
+                },
+            )
+        }
+    };
+
+    if sum_input != half_sum {
+        return Err(BitFault::Swap(AdderRole::HalfAdder, half_sum, sum_input));
+    }
+
+    if sum_gate.output != z {
+        return Err(BitFault::Swap(AdderRole::Sum, sum_gate.output, z));
+    }
+
+    let carry_and: WireId = find_gate(netlist, Operation::And, half_sum, carry)
+        .ok_or(BitFault::Unrepairable(AdderRole::Carry))?;
+
+    match (
+        find_gate_with_input(netlist, Operation::Or, carry_and),
+        find_gate_with_input(netlist, Operation::Or, half_carry),
+    ) {
+        (Some((_, other)), _) if other != half_carry => {
+            Err(BitFault::Swap(AdderRole::HalfAdder, half_carry, other))
+        }
+        (Some((carry_gate, _)), _) => Ok(carry_gate.output),
+        (None, Some((_, other))) => Err(BitFault::Swap(AdderRole::Carry, carry_and, other)),
+        (None, None) => Err(BitFault::Unrepairable(AdderRole::Carry)),
+    }
+}
+
+fn get_test_cases(bits: usize) -> Vec<(u64, u64)> {
+    let mask: u64 = (1 << bits) - 1;
+    let mut cases: Vec<(u64, u64)> = vec![(0, 0), (mask, 1), (mask, mask)];
+
+    for bit in 0..bits {
+        cases.extend([(1 << bit, 0), (0, 1 << bit), (1 << bit, 1 << bit)]);
+    }
+
+    let mut state: u64 = 0x2024_1224;
+
+    for _ in 0..32 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        cases.push((state & mask, state >> 32 & mask));
+    }
+
+    cases
+}
+
+fn verify_addition(netlist: &Netlist, bits: usize) -> Result<(), AdderError> {
+    for (x, y) in get_test_cases(bits) {
+        let z: u64 = netlist.simulate(&[('x', x), ('y', y)])?.read_number('z')?;
+
+        if z != x + y {
+            return Err(AdderError::IncorrectSum { x, y, z });
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn repair_adder(netlist: &Netlist) -> Result<AdderReport, AdderError> {
+    let (x, y, z) = (
+        netlist.bit_wires('x'),
+        netlist.bit_wires('y'),
+        netlist.bit_wires('z'),
+    );
+
+    if x.len() != y.len() || z.len() != x.len() + 1 {
+        return Err(AdderError::WidthMismatch {
+            x: x.len(),
+            y: y.len(),
+            z: z.len(),
+        });
+    }
+
+    let bits: usize = x.len();
+
+    if !(1..u64::BITS as usize).contains(&bits) {
+        return Err(AdderError::UnsupportedWidth { bits });
+    }
+
+    let mut netlist: Netlist = netlist.clone();
+    let mut repairs: Vec<Repair> = Vec::new();
+    let mut carry: Option<WireId> = None;
+
+    for bit in 0..=bits {
+        for attempt in 0..=MAX_REPAIRS_PER_BIT {
+            let fault: BitFault = match (bit < bits, carry) {
+                (true, _) => match check_bit(&netlist, [x[bit], y[bit], z[bit]], carry) {
+                    Ok(next_carry) => {
+                        carry = Some(next_carry);
+                        break;
+                    }
+                    Err(fault) => fault,
+                },
+                (false, Some(carry)) if carry != z[bit] => {
+                    BitFault::Swap(AdderRole::Carry, carry, z[bit])
+                }
+                (false, _) => break,
+            };
+
+            let (role, first, second) = match fault {
+                BitFault::Swap(role, first, second) if attempt < MAX_REPAIRS_PER_BIT => {
+                    (role, first, second)
+                }
+                BitFault::Swap(role, ..) | BitFault::Unrepairable(role) => {
+                    return Err(AdderError::Unrepairable { bit, role })
+                }
+            };
+
+            netlist.swap_outputs(first, second);
+            repairs.push(Repair {
+                bit,
+                role,
+                wires: [
+                    netlist.wire_name(first).to_owned(),
+                    netlist.wire_name(second).to_owned(),
+                ],
+            });
+
+            if bit == bits {
+                carry = Some(second);
+            }
+        }
+    }
+
+    verify_addition(&netlist, bits)?;
+
+    Ok(AdderReport { bits, repairs })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_24_crossed_wires::adder::*;
+    use crate::day_24_crossed_wires::netlist::parse_netlist;
+
+    fn build_adder(bits: usize, swaps: &[(&str, &str)]) -> String {
+        let mut gates: Vec<String> = vec![
+            String::from("x00 XOR y00 -> z00"),
+            String::from("x00 AND y00 -> c00"),
+        ];
+
+        for bit in 1..bits {
+            gates.extend([
+                format!("x{0:02} XOR y{0:02} -> s{0:02}", bit),
+                format!("x{0:02} AND y{0:02} -> h{0:02}", bit),
+                format!("c{1:02} XOR s{0:02} -> z{0:02}", bit, bit - 1),
+                format!("s{0:02} AND c{1:02} -> a{0:02}", bit, bit - 1),
+                format!("h{0:02} OR a{0:02} -> c{0:02}", bit),
+            ]);
+        }
+
+        let mut lines: Vec<String> = (0..bits)
+            .flat_map(|bit| [format!("x{:02}: 0", bit), format!("y{:02}: 0", bit)])
+            .collect();
+        lines.push(String::new());
+
+        for gate in gates {
+            let (inputs, output) = gate.split_once(" -> ").unwrap();
+            let output: &str = swaps
+                .iter()
+                .find_map(|&(first, second)| match output {
+                    _ if output == first => Some(second),
+                    _ if output == second => Some(first),
+                    _ => None,
+                })
+                .unwrap_or(output);
+            lines.push(format!("{} -> {}", inputs, output));
+        }
+
+        lines
+            .join("\n")
+            .replace(&format!("c{:02}", bits - 1), &format!("z{:02}", bits))
+    }
+
+    #[test]
+    fn repair_swapped_adder_outputs() {
+        let netlist = parse_netlist(&build_adder(6, &[])).unwrap();
+        let report = repair_adder(&netlist).unwrap();
+        assert!(report.repairs.is_empty());
+
+        let swaps = [("z01", "s02"), ("s03", "h03"), ("z04", "c04")];
+        let netlist = parse_netlist(&build_adder(6, &swaps)).unwrap();
+        let broken_sum = netlist
+            .simulate(&[('x', 2), ('y', 1)])
+            .unwrap()
+            .read_number('z');
+        assert_ne!(broken_sum, Ok(3));
+
+        let report = repair_adder(&netlist).unwrap();
+        assert_eq!(
+            report.swapped_wires(),
+            ["c04", "h03", "s02", "s03", "z01", "z04"]
+        );
+        assert_eq!(
+            report.repairs[0],
+            Repair {
+                bit: 1,
+                role: AdderRole::Sum,
+                wires: [String::from("s02"), String::from("z01")]
+            }
+        );
+        assert_eq!(
+            report.to_string().lines().nth(2),
+            Some("bit  3: broken half-adder gate, swap outputs h03 and s03")
+        );
+
+        let netlist = parse_netlist("x00: 1\ny00: 1\n\nx00 AND y00 -> z00").unwrap();
+        assert_eq!(
+            repair_adder(&netlist).unwrap_err(),
+            AdderError::WidthMismatch { x: 1, y: 1, z: 1 }
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use crate::day_24_crossed_wires::netlist::{Netlist, Operation, WireId};
+use std::collections::HashSet;
+
+const VERILOG_KEYWORDS: &str = "\
+accept_on alias always always_comb always_ff always_latch and assert assign assume automatic \
+before begin bind bins binsof bit break buf bufif0 bufif1 byte case casex casez cell chandle \
+checker class clocking cmos config const constraint context continue cover covergroup \
+coverpoint cross deassign default defparam design disable dist do edge else end endcase \
+endchecker endclass endclocking endconfig endfunction endgenerate endgroup endinterface \
+endmodule endpackage endprimitive endprogram endproperty endsequence endspecify endtable \
+endtask enum event eventually expect export extends extern final first_match for force foreach \
+forever fork forkjoin function generate genvar global highz0 highz1 if iff ifnone ignore_bins \
+illegal_bins implements implies import incdir include initial inout input inside instance int \
+integer interconnect interface intersect join join_any join_none large let liblist library \
+local localparam logic longint macromodule matches medium modport module nand negedge nettype \
+new nexttime nmos nor noshowcancelled not notif0 notif1 null or output package packed \
+parameter pmos posedge primitive priority program property protected pull0 pull1 pulldown \
+pullup pulsestyle_ondetect pulsestyle_onevent pure rand randc randcase randsequence rcmos real \
+realtime ref reg reject_on release repeat restrict return rnmos rpmos rtran rtranif0 rtranif1 \
+s_always s_eventually s_nexttime s_until s_until_with scalared sequence shortint shortreal \
+showcancelled signed small soft solve specify specparam static string strong strong0 strong1 \
+struct super supply0 supply1 sync_accept_on sync_reject_on table tagged task this throughout \
+time timeprecision timeunit tran tranif0 tranif1 tri tri0 tri1 triand trior trireg type \
+typedef union unique unique0 unsigned until until_with untyped use uwire var vectored virtual \
+void wait wait_order wand weak weak0 weak1 while wildcard wire with within wor xnor xor";
+
+fn is_simple_identifier(name: &str) -> bool {
+    name.starts_with(|char: char| char.is_ascii_alphabetic() || char == '_')
+        && name
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '$')
+}
+
+fn get_verilog_name(name: &str) -> String {
+    match VERILOG_KEYWORDS
+        .split_whitespace()
+        .any(|keyword| keyword == name)
+        || !is_simple_identifier(name)
+    {
+        true => format!("\\{} ", name),
+        false => name.to_owned(),
+    }
+}
+
+fn get_verilog_primitive(operation: Operation) -> &'static str {
+    match operation {
+        Operation::And => "and",
+        Operation::Or => "or",
+        Operation::Xor => "xor",
+    }
+}
+
+fn get_driven_wires(netlist: &Netlist) -> HashSet<WireId> {
+    netlist.gates().iter().map(|gate| gate.output).collect()
+}
+
+pub(crate) fn to_dot(netlist: &Netlist, highlighted: &[&str]) -> String {
+    let driven: HashSet<WireId> = get_driven_wires(netlist);
+    let mut lines: Vec<String> = vec![
+        String::from("digraph crossed_wires {"),
+        String::from("    rankdir=LR;"),
+    ];
+
+    for id in netlist.wires() {
+        let name: &str = netlist.wire_name(id);
+        let mut attributes: Vec<&str> = match (driven.contains(&id), name.starts_with('z')) {
+            (false, _) => vec!["shape=invhouse"],
+            (true, true) => vec!["shape=house"],
+            (true, false) => vec!["shape=ellipse"],
+        };
+
+        if highlighted.contains(&name) {
+            attributes.extend(["style=filled", "fillcolor=\"#ff8080\"", "color=red"]);
+        }
+
+        lines.push(format!("    \"{}\" [{}];", name, attributes.join(", ")));
+    }
+
+    for (index, gate) in netlist.gates().iter().enumerate() {
+        let [input_1, input_2] = gate.inputs.map(|id| netlist.wire_name(id));
+        lines.push(format!(
+            "    g{} [label=\"{}\", shape=box];",
+            index, gate.operation
+        ));
+        lines.push(format!(
+            "    \"{}\" -> g{}; \"{}\" -> g{}; g{} -> \"{}\";",
+            input_1,
+            index,
+            input_2,
+            index,
+            index,
+            netlist.wire_name(gate.output)
+        ));
+    }
+
+    lines.push(String::from("}"));
+    lines.join("\n")
+}
+
+pub(crate) fn to_verilog(netlist: &Netlist, module_name: &str) -> String {
+    let driven: HashSet<WireId> = get_driven_wires(netlist);
+    let mut ports: Vec<String> = Vec::new();
+    let mut internal_wires: Vec<String> = Vec::new();
+
+    for id in netlist.wires() {
+        let name: String = get_verilog_name(netlist.wire_name(id));
+
+        match (driven.contains(&id), name.starts_with('z')) {
+            (false, _) => ports.push(format!("    input wire {}", name)),
+            (true, true) => ports.push(format!("    output wire {}", name)),
+            (true, false) => internal_wires.push(format!("    wire {};", name)),
+        }
+    }
+
+    let mut lines: Vec<String> = vec![
+        format!("module {} (", module_name),
+        ports.join(",\n"),
+        String::from(");"),
+    ];
+    lines.extend(internal_wires);
+
+    for (index, gate) in netlist.gates().iter().enumerate() {
+        let [input_1, input_2] = gate
+            .inputs
+            .map(|id| get_verilog_name(netlist.wire_name(id)));
+        lines.push(format!(
+            "    {} g{} ({}, {}, {});",
+            get_verilog_primitive(gate.operation),
+            index,
+            get_verilog_name(netlist.wire_name(gate.output)),
+            input_1,
+            input_2
+        ));
+    }
+
+    lines.push(String::from("endmodule"));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_24_crossed_wires::export::*;
+    use crate::day_24_crossed_wires::netlist::parse_netlist;
+
+    #[test]
+    fn export_circuit_as_dot_and_verilog() {
+        let netlist =
+            parse_netlist("x00: 1\ny00: 0\n\nx00 AND y00 -> or\nor XOR x00 -> z00").unwrap();
+
+        let dot = to_dot(&netlist, &["z00"]);
+        assert_eq!(
+            dot.lines().collect::<Vec<_>>(),
+            [
+                "digraph crossed_wires {",
+                "    rankdir=LR;",
+                "    \"or\" [shape=ellipse];",
+                "    \"x00\" [shape=invhouse];",
+                "    \"y00\" [shape=invhouse];",
+                "    \"z00\" [shape=house, style=filled, fillcolor=\"#ff8080\", color=red];",
+                "    g0 [label=\"AND\", shape=box];",
+                "    \"x00\" -> g0; \"y00\" -> g0; g0 -> \"or\";",
+                "    g1 [label=\"XOR\", shape=box];",
+                "    \"or\" -> g1; \"x00\" -> g1; g1 -> \"z00\";",
+                "}",
+            ]
+        );
+
+        let verilog = to_verilog(&netlist, "crossed_wires");
+        assert_eq!(
+            verilog.lines().collect::<Vec<_>>(),
+            [
+                "module crossed_wires (",
+                "    input wire x00,",
+                "    input wire y00,",
+                "    output wire z00",
+                ");",
+                "    wire \\or ;",
+                "    and g0 (\\or , x00, y00);",
+                "    xor g1 (z00, \\or , x00);",
+                "endmodule",
+            ]
+        );
+
+        let netlist = parse_netlist("reg: 1\ntri: 0\n\nreg OR tri -> z00").unwrap();
+        let verilog = to_verilog(&netlist, "crossed_wires");
+        assert_eq!(
+            verilog.lines().collect::<Vec<_>>(),
+            [
+                "module crossed_wires (",
+                "    input wire \\reg ,",
+                "    input wire \\tri ,",
+                "    output wire z00",
+                ");",
+                "    or g0 (z00, \\reg , \\tri );",
+                "endmodule",
+            ]
+        );
+        assert_eq!(get_verilog_name("1ab"), "\\1ab ");
+    }
+}
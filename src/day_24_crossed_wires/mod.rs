@@ -1,2 +1,44 @@
+use crate::cli::CircuitAction;
+use crate::solution::Registry;
+
+pub(crate) mod adder;
+pub(crate) mod export;
+pub(crate) mod netlist;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+
+pub(crate) fn register(registry: &mut Registry) {
+    registry.register(part_1::Part1);
+    registry.register(part_2::Part2);
+}
+
+pub(crate) fn run_circuit_command(action: CircuitAction, file_content: &str) -> bool {
+    let netlist: netlist::Netlist = match netlist::parse_netlist(file_content) {
+        Ok(netlist) => netlist,
+        Err(error) => {
+            eprintln!("{}", error);
+            return false;
+        }
+    };
+
+    match action {
+        CircuitAction::Repair => match adder::repair_adder(&netlist) {
+            Ok(report) => println!("{}", report),
+            Err(error) => {
+                eprintln!("{}", error);
+                return false;
+            }
+        },
+        CircuitAction::Dot => {
+            let report: Option<adder::AdderReport> = adder::repair_adder(&netlist).ok();
+            let suspected: Vec<&str> = report
+                .as_ref()
+                .map(|report| report.swapped_wires())
+                .unwrap_or_default();
+            println!("{}", export::to_dot(&netlist, &suspected));
+        }
+        CircuitAction::Verilog => println!("{}", export::to_verilog(&netlist, "crossed_wires")),
+    }
+
+    true
+}
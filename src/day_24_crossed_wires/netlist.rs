@@ -0,0 +1,395 @@
+use crate::parsing::{split_at_delimiter, ParseError};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) struct WireId(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Operation {
+    And,
+    Or,
+    Xor,
+}
+
+impl Operation {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "AND" => Some(Operation::And),
+            "OR" => Some(Operation::Or),
+            "XOR" => Some(Operation::Xor),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn evaluate(self, input_1: bool, input_2: bool) -> bool {
+        match self {
+            Operation::And => input_1 & input_2,
+            Operation::Or => input_1 | input_2,
+            Operation::Xor => input_1 ^ input_2,
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operation::And => write!(f, "AND"),
+            Operation::Or => write!(f, "OR"),
+            Operation::Xor => write!(f, "XOR"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Gate {
+    pub(crate) inputs: [WireId; 2],
+    pub(crate) operation: Operation,
+    pub(crate) output: WireId,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum NetlistError {
+    Cycle {
+        wires: Vec<String>,
+    },
+    UndrivenWires {
+        wires: Vec<String>,
+    },
+    NumberTooWide {
+        prefix: char,
+        value: u64,
+        bits: usize,
+    },
+}
+
+impl fmt::Display for NetlistError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NetlistError::Cycle { wires } => {
+                write!(f, "gates driving {} form a cycle", wires.join(", "))
+            }
+            NetlistError::UndrivenWires { wires } => {
+                write!(f, "wires {} can never be driven", wires.join(", "))
+            }
+            NetlistError::NumberTooWide {
+                prefix,
+                value,
+                bits,
+            } => write!(
+                f,
+                "{} does not fit in the {} '{}' wires of the circuit",
+                value, bits, prefix
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NetlistError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Netlist {
+    names: Vec<String>,
+    ids: HashMap<String, WireId>,
+    initial_values: Vec<Option<bool>>,
+    gates: Vec<Gate>,
+}
+
+impl Netlist {
+    fn new() -> Self {
+        Netlist {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            initial_values: Vec::new(),
+            gates: Vec::new(),
+        }
+    }
+
+    fn intern(&mut self, name: &str) -> WireId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+
+        let id: WireId = WireId(self.names.len());
+        self.names.push(name.to_owned());
+        self.ids.insert(name.to_owned(), id);
+        self.initial_values.push(None);
+        id
+    }
+
+    pub(crate) fn wire_name(&self, id: WireId) -> &str {
+        &self.names[id.0]
+    }
+
+    pub(crate) fn wires(&self) -> Vec<WireId> {
+        let mut wires: Vec<WireId> = self.ids.values().copied().collect();
+        wires.sort_unstable_by_key(|&id| self.wire_name(id));
+        wires
+    }
+
+    pub(crate) fn gates(&self) -> &[Gate] {
+        &self.gates
+    }
+
+    pub(crate) fn swap_outputs(&mut self, first: WireId, second: WireId) {
+        for gate in self.gates.iter_mut() {
+            if gate.output == first {
+                gate.output = second;
+            } else if gate.output == second {
+                gate.output = first;
+            }
+        }
+    }
+
+    pub(crate) fn bit_wires(&self, prefix: char) -> Vec<WireId> {
+        let mut wires: Vec<(u32, WireId)> = self
+            .ids
+            .iter()
+            .filter_map(|(name, &id)| {
+                let bit: u32 = name.strip_prefix(prefix)?.parse().ok()?;
+                Some((bit, id))
+            })
+            .collect();
+
+        wires.sort_unstable();
+        wires.into_iter().map(|(_, id)| id).collect()
+    }
+
+    fn find_evaluation_order(&self) -> Result<Vec<Gate>, NetlistError> {
+        let mut gates_by_input: HashMap<WireId, Vec<usize>> = HashMap::new();
+        let mut pending_inputs: Vec<usize> = vec![0; self.gates.len()];
+        let drivers: HashMap<WireId, usize> = self
+            .gates
+            .iter()
+            .enumerate()
+            .map(|(index, gate)| (gate.output, index))
+            .collect();
+
+        for (index, gate) in self.gates.iter().enumerate() {
+            for input in gate.inputs {
+                if drivers.contains_key(&input) {
+                    gates_by_input.entry(input).or_default().push(index);
+                    pending_inputs[index] += 1;
+                }
+            }
+        }
+
+        let mut ready: VecDeque<usize> = (0..self.gates.len())
+            .filter(|&index| pending_inputs[index] == 0)
+            .collect();
+        let mut order: Vec<Gate> = Vec::with_capacity(self.gates.len());
+
+        while let Some(index) = ready.pop_front() {
+            let gate: Gate = self.gates[index];
+            order.push(gate);
+
+            for &next in gates_by_input.get(&gate.output).into_iter().flatten() {
+                pending_inputs[next] -= 1;
+
+                if pending_inputs[next] == 0 {
+                    ready.push_back(next);
+                }
+            }
+        }
+
+        if order.len() < self.gates.len() {
+            let mut wires: Vec<String> = (0..self.gates.len())
+                .filter(|&index| pending_inputs[index] > 0)
+                .map(|index| self.wire_name(self.gates[index].output).to_owned())
+                .collect();
+            wires.sort_unstable();
+            return Err(NetlistError::Cycle { wires });
+        }
+
+        Ok(order)
+    }
+
+    pub(crate) fn simulate(&self, numbers: &[(char, u64)]) -> Result<Simulation<'_>, NetlistError> {
+        let mut values: Vec<Option<bool>> = self.initial_values.clone();
+
+        for &(prefix, value) in numbers {
+            let wires: Vec<WireId> = self.bit_wires(prefix);
+
+            if wires.len() < u64::BITS as usize && value >> wires.len() != 0 {
+                return Err(NetlistError::NumberTooWide {
+                    prefix,
+                    value,
+                    bits: wires.len(),
+                });
+            }
+
+            for (bit, id) in wires.into_iter().enumerate() {
+                values[id.0] = Some(bit < u64::BITS as usize && value >> bit & 1 == 1);
+            }
+        }
+
+        for gate in self.find_evaluation_order()? {
+            let [input_1, input_2] = gate.inputs;
+
+            if let (Some(input_1), Some(input_2)) = (values[input_1.0], values[input_2.0]) {
+                values[gate.output.0] = Some(gate.operation.evaluate(input_1, input_2));
+            }
+        }
+
+        Ok(Simulation {
+            netlist: self,
+            values,
+        })
+    }
+}
+
+pub(crate) struct Simulation<'a> {
+    netlist: &'a Netlist,
+    values: Vec<Option<bool>>,
+}
+
+impl Simulation<'_> {
+    pub(crate) fn read_number(&self, prefix: char) -> Result<u64, NetlistError> {
+        let wires: Vec<WireId> = self.netlist.bit_wires(prefix);
+        let undriven: Vec<String> = wires
+            .iter()
+            .filter(|id| self.values[id.0].is_none())
+            .map(|&id| self.netlist.wire_name(id).to_owned())
+            .collect();
+
+        if !undriven.is_empty() {
+            return Err(NetlistError::UndrivenWires { wires: undriven });
+        }
+
+        if wires.len() > u64::BITS as usize {
+            return Err(NetlistError::NumberTooWide {
+                prefix,
+                value: u64::MAX,
+                bits: wires.len(),
+            });
+        }
+
+        Ok(wires
+            .iter()
+            .enumerate()
+            .map(|(bit, id)| (self.values[id.0] == Some(true)) as u64 * (1 << bit))
+            .sum())
+    }
+}
+
+fn parse_gate(netlist: &mut Netlist, line_index: usize, line: &str) -> Result<Gate, ParseError> {
+    let (inputs, output) =
+        split_at_delimiter(line_index, line, line, " -> ", "a gate followed by ' -> '")?;
+
+    match inputs.split(' ').collect::<Vec<&str>>()[..] {
+        [input_1, operation, input_2] => {
+            let operation: Operation = Operation::from_name(operation).ok_or_else(|| {
+                ParseError::invalid_value(line_index, line, operation, "one of AND, OR, XOR")
+            })?;
+
+            Ok(Gate {
+                inputs: [netlist.intern(input_1), netlist.intern(input_2)],
+                operation,
+                output: netlist.intern(output),
+            })
+        }
+        _ => Err(ParseError::unexpected_format(
+            line_index,
+            line,
+            inputs,
+            "two wires joined by a gate",
+        )),
+    }
+}
+
+pub(crate) fn parse_netlist(file_content: &str) -> Result<Netlist, ParseError> {
+    let mut lines = file_content.lines().enumerate();
+    let mut netlist: Netlist = Netlist::new();
+    let mut driven: Vec<bool> = Vec::new();
+
+    for (line_index, line) in lines.by_ref().take_while(|(_, line)| !line.is_empty()) {
+        let (name, value) = split_at_delimiter(line_index, line, line, ": ", "wire: value")?;
+
+        let value: bool = match value {
+            "0" => false,
+            "1" => true,
+            _ => return Err(ParseError::invalid_value(line_index, line, value, "0 or 1")),
+        };
+
+        let id: WireId = netlist.intern(name);
+        netlist.initial_values[id.0] = Some(value);
+    }
+
+    for (line_index, line) in lines {
+        let gate: Gate = parse_gate(&mut netlist, line_index, line)?;
+        driven.resize(netlist.names.len(), false);
+
+        if driven[gate.output.0] || netlist.initial_values[gate.output.0].is_some() {
+            let (_, output) = line.rsplit_once(' ').unwrap_or_default();
+            return Err(ParseError::invalid_value(
+                line_index,
+                line,
+                output,
+                "a wire without another driver",
+            ));
+        }
+
+        driven[gate.output.0] = true;
+        netlist.gates.push(gate);
+    }
+
+    Ok(netlist)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_24_crossed_wires::netlist::*;
+
+    #[test]
+    fn simulate_with_numbers_and_report_faults() {
+        let file_content = std::fs::read_to_string("./test_input/24_01.txt").unwrap();
+        let netlist = parse_netlist(&file_content).unwrap();
+        assert_eq!(netlist.simulate(&[]).unwrap().read_number('z'), Ok(4));
+
+        let simulation = netlist.simulate(&[('x', 0b101), ('y', 0b011)]).unwrap();
+        assert_eq!(simulation.read_number('z'), Ok(0b111));
+        assert_eq!(
+            netlist.simulate(&[('x', 8)]).err(),
+            Some(NetlistError::NumberTooWide {
+                prefix: 'x',
+                value: 8,
+                bits: 3
+            })
+        );
+
+        let netlist = parse_netlist("x00: 1\n\nx00 AND a -> b\nb OR x00 -> a\nx00 OR c -> z00");
+        assert_eq!(
+            netlist.unwrap().simulate(&[]).err().unwrap().to_string(),
+            "gates driving a, b form a cycle"
+        );
+
+        let netlist = parse_netlist("x00: 1\n\nx00 AND c -> z00\nx00 OR x00 -> z01").unwrap();
+        assert_eq!(
+            netlist.simulate(&[]).unwrap().read_number('z'),
+            Err(NetlistError::UndrivenWires {
+                wires: vec![String::from("z00")]
+            })
+        );
+    }
+
+    #[test]
+    fn reject_invalid_data() {
+        let error = parse_netlist("x00: 1\ny00: 2\n\nx00 AND y00 -> z00");
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "Line 2, column 6: invalid value '2', expected 0 or 1"
+        );
+
+        let error = parse_netlist("x00: 1\ny00: 0\n\nx00 NAND y00 -> z00");
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "Line 4, column 5: invalid value 'NAND', expected one of AND, OR, XOR"
+        );
+
+        let error = parse_netlist("x00: 1\ny00: 0\n\nx00 AND y00 -> z00\nx00 OR y00 -> z00");
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "Line 5, column 15: invalid value 'z00', expected a wire without another driver"
+        );
+    }
+}
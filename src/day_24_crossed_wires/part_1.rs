@@ -1,96 +1,44 @@
-use std::collections::{HashMap, VecDeque};
+use crate::day_24_crossed_wires::netlist::{parse_netlist, Netlist, NetlistError};
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
 
-fn process_data(file_path: &str) -> (HashMap<String, bool>, VecDeque<[String; 4]>) {
-    let file_content = std::fs::read_to_string(file_path).expect("Should open a file");
-    let mut lines = file_content.lines();
+fn get_decimal_number_output_on_z_wires(netlist: &Netlist) -> Result<u64, NetlistError> {
+    netlist.simulate(&[])?.read_number('z')
+}
 
-    let wire_values: HashMap<String, bool> = lines
-        .by_ref()
-        .take_while(|line| !line.is_empty())
-        .filter_map(|line| line.split_once(": "))
-        .map(|(key, value)| {
-            (
-                key.to_owned(),
-                value.parse::<u8>().expect("Should be 0 or 1") != 0,
-            )
-        })
-        .collect();
+pub(crate) struct Part1;
 
-    let gates: VecDeque<[String; 4]> = lines
-        .map(|line| {
-            line.replace("-> ", "")
-                .split(' ')
-                .map(|part| part.to_owned())
-                .collect::<Vec<String>>()
-                .try_into()
-                .expect("Should contain four String values")
-        })
-        .collect();
+impl Solution for Part1 {
+    type Parsed = Netlist;
 
-    (wire_values, gates)
-}
+    const DAY: u8 = 24;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Crossed Wires";
 
-fn evaluate(input1: bool, operation: &str, input2: bool) -> bool {
-    match operation {
-        "AND" => input1 & input2,
-        "OR" => input1 | input2,
-        "XOR" => input1 ^ input2,
-        _ => unreachable!(),
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_netlist(input)
     }
-}
 
-fn get_values_for_all_wires(
-    wire_values: &mut HashMap<String, bool>,
-    gates: &mut VecDeque<[String; 4]>,
-) {
-    while let Some([input1, operation, input2, output]) = gates.pop_front() {
-        if let Some(&input1) = wire_values.get(&input1) {
-            if let Some(&input2) = wire_values.get(&input2) {
-                wire_values.insert(output, evaluate(input1, &operation, input2));
-                continue;
-            }
-        }
-
-        gates.push_back([input1, operation, input2, output]);
+    fn solve(&self, netlist: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: u64 = get_decimal_number_output_on_z_wires(netlist)?;
+        Ok(Answer::from(result))
     }
 }
 
-fn get_decimal_number_output_on_z_wires(known_values: &HashMap<String, bool>) -> u64 {
-    known_values
-        .iter()
-        .filter_map(|(key, value)| {
-            key.strip_prefix('z').and_then(|number| {
-                number
-                    .parse::<u32>()
-                    .ok()
-                    .map(|exponent| 2_u64.pow(exponent) * *value as u64)
-            })
-        })
-        .sum()
-}
-
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let (mut wire_values, mut gates): (HashMap<String, bool>, VecDeque<[String; 4]>) =
-        process_data("./input/24.txt");
-    get_values_for_all_wires(&mut wire_values, &mut gates);
-    let result: u64 = get_decimal_number_output_on_z_wires(&wire_values);
-    Ok(format!("Day 24 Crossed Wires (Part 1): {}.", result))
-}
-
 #[cfg(test)]
 mod tests {
     use crate::day_24_crossed_wires::part_1::*;
 
     #[test]
     fn solve_with_test_data() {
-        let (mut wire_values, mut gates) = process_data("./test_input/24_01.txt");
-        get_values_for_all_wires(&mut wire_values, &mut gates);
-        let result = get_decimal_number_output_on_z_wires(&wire_values);
-        assert_eq!(result, 4);
-
-        let (mut wire_values, mut gates) = process_data("./test_input/24_02.txt");
-        get_values_for_all_wires(&mut wire_values, &mut gates);
-        let result = get_decimal_number_output_on_z_wires(&wire_values);
-        assert_eq!(result, 2024);
+        let file_content = std::fs::read_to_string("./test_input/24_01.txt").unwrap();
+        let netlist = parse_netlist(&file_content).unwrap();
+        let result = get_decimal_number_output_on_z_wires(&netlist);
+        assert_eq!(result, Ok(4));
+
+        let file_content = std::fs::read_to_string("./test_input/24_02.txt").unwrap();
+        let netlist = parse_netlist(&file_content).unwrap();
+        let result = get_decimal_number_output_on_z_wires(&netlist);
+        assert_eq!(result, Ok(2024));
     }
 }
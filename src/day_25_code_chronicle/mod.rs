@@ -1 +1,7 @@
+use crate::solution::Registry;
+
 pub(crate) mod part_1;
+
+pub(crate) fn register(registry: &mut Registry) {
+    registry.register(part_1::Part1);
+}
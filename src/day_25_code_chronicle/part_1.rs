@@ -1,23 +1,45 @@
-fn process_data(file_path: &str) -> Vec<Vec<u8>> {
-    std::fs::read_to_string(file_path)
-        .expect("Should open a file")
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
+
+fn process_data(file_content: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+    let lines: Vec<(usize, &str)> = file_content
         .lines()
-        .filter(|line| !line.is_empty())
-        .collect::<Vec<&str>>()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .collect();
+
+    lines
         .chunks(7)
         .map(|chunk| {
+            if chunk.len() != 7 {
+                return Err(ParseError::missing_element(
+                    "seven rows for every lock and key",
+                ));
+            }
+
             chunk
                 .iter()
-                .map(|line| {
-                    line.chars()
-                        .rev()
-                        .enumerate()
-                        .filter_map(|(i, char)| match char {
-                            '#' => Some(2u8.pow(i as u32)),
-                            '.' => None,
-                            _ => panic!("Invalid input character: {}", char),
-                        })
-                        .sum()
+                .map(|&(line_index, line)| {
+                    let mut row: u8 = 0;
+                    let width: usize = line.chars().count();
+
+                    for (position, (i, char)) in line.char_indices().enumerate() {
+                        match char {
+                            '#' => row += 2u8.pow((width - 1 - position) as u32),
+                            '.' => {}
+                            _ => {
+                                let token: &str = &line[i..i + char.len_utf8()];
+                                return Err(ParseError::invalid_value(
+                                    line_index,
+                                    line,
+                                    token,
+                                    "'#' or '.'",
+                                ));
+                            }
+                        }
+                    }
+
+                    Ok(row)
                 })
                 .collect()
         })
@@ -38,10 +60,23 @@ fn count_keys_that_can_be_inserted_into_locks(locks_and_keys: Vec<Vec<u8>>) -> u
         .count()
 }
 
-pub(crate) fn solve() -> Result<String, Box<dyn std::error::Error>> {
-    let locks_and_keys: Vec<Vec<u8>> = process_data("./input/25.txt");
-    let result: usize = count_keys_that_can_be_inserted_into_locks(locks_and_keys);
-    Ok(format!("Day 25 Code Chronicle (Part 1): {}.", result))
+pub(crate) struct Part1;
+
+impl Solution for Part1 {
+    type Parsed = Vec<Vec<u8>>;
+
+    const DAY: u8 = 25;
+    const PART: u8 = 1;
+    const TITLE: &'static str = "Code Chronicle";
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        process_data(input)
+    }
+
+    fn solve(&self, locks_and_keys: &Self::Parsed) -> Result<Answer, Box<dyn std::error::Error>> {
+        let result: usize = count_keys_that_can_be_inserted_into_locks(locks_and_keys.clone());
+        Ok(Answer::from(result))
+    }
 }
 
 #[cfg(test)]
@@ -50,7 +85,8 @@ mod tests {
 
     #[test]
     fn solve_with_test_data() {
-        let locks_and_keys = process_data("./test_input/25.txt");
+        let file_content = std::fs::read_to_string("./test_input/25.txt").unwrap();
+        let locks_and_keys = process_data(&file_content).unwrap();
         let result = count_keys_that_can_be_inserted_into_locks(locks_and_keys);
         assert_eq!(result, 3);
     }
@@ -0,0 +1,171 @@
+use crate::grid::Point;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) enum Direction {
+    East,
+    South,
+    West,
+    North,
+}
+
+impl Direction {
+    pub(crate) const ALL: [Direction; 4] = [
+        Direction::East,
+        Direction::South,
+        Direction::West,
+        Direction::North,
+    ];
+
+    pub(crate) fn rotate_left(self) -> Self {
+        match self {
+            Direction::East => Direction::North,
+            Direction::North => Direction::West,
+            Direction::West => Direction::South,
+            Direction::South => Direction::East,
+        }
+    }
+
+    pub(crate) fn rotate_right(self) -> Self {
+        match self {
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+            Direction::North => Direction::East,
+        }
+    }
+
+    pub(crate) fn opposite(self) -> Self {
+        self.rotate_left().rotate_left()
+    }
+
+    pub(crate) fn delta(self) -> Point {
+        match self {
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, 1),
+            Direction::West => Point::new(-1, 0),
+            Direction::North => Point::new(0, -1),
+        }
+    }
+
+    pub(crate) fn from_delta(delta: Point) -> Option<Self> {
+        Direction::ALL
+            .into_iter()
+            .find(|direction| direction.delta() == delta)
+    }
+
+    pub(crate) fn from_char(char: char) -> Option<Self> {
+        match char {
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '<' => Some(Direction::West),
+            '^' => Some(Direction::North),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_char(self) -> char {
+        match self {
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+            Direction::North => '^',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) enum Direction8 {
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+    North,
+    NorthEast,
+}
+
+impl Direction8 {
+    pub(crate) const ALL: [Direction8; 8] = [
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+        Direction8::North,
+        Direction8::NorthEast,
+    ];
+
+    fn turn(self, steps: usize) -> Self {
+        Direction8::ALL[(self as usize + steps) % Direction8::ALL.len()]
+    }
+
+    pub(crate) fn rotate_left(self) -> Self {
+        self.turn(Direction8::ALL.len() - 1)
+    }
+
+    pub(crate) fn rotate_right(self) -> Self {
+        self.turn(1)
+    }
+
+    pub(crate) fn opposite(self) -> Self {
+        self.turn(Direction8::ALL.len() / 2)
+    }
+
+    pub(crate) fn delta(self) -> Point {
+        match self {
+            Direction8::East => Point::new(1, 0),
+            Direction8::SouthEast => Point::new(1, 1),
+            Direction8::South => Point::new(0, 1),
+            Direction8::SouthWest => Point::new(-1, 1),
+            Direction8::West => Point::new(-1, 0),
+            Direction8::NorthWest => Point::new(-1, -1),
+            Direction8::North => Point::new(0, -1),
+            Direction8::NorthEast => Point::new(1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::East => Direction8::East,
+            Direction::South => Direction8::South,
+            Direction::West => Direction8::West,
+            Direction::North => Direction8::North,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::direction::*;
+
+    #[test]
+    fn rotate_and_parse_directions() {
+        assert_eq!(Direction::North.rotate_right(), Direction::East);
+        assert_eq!(Direction::North.rotate_left(), Direction::West);
+        assert_eq!(Direction::North.opposite(), Direction::South);
+        assert_eq!(Direction::from_char('v'), Some(Direction::South));
+        assert_eq!(Direction::from_char('x'), None);
+
+        for direction in Direction::ALL {
+            assert_eq!(Direction::from_char(direction.to_char()), Some(direction));
+            assert_eq!(Direction::from_delta(direction.delta()), Some(direction));
+            assert_eq!(Direction8::from(direction).delta(), direction.delta());
+        }
+
+        assert_eq!(Direction8::North.rotate_right(), Direction8::NorthEast);
+        assert_eq!(Direction8::East.rotate_left(), Direction8::NorthEast);
+        assert_eq!(Direction8::SouthWest.opposite(), Direction8::NorthEast);
+
+        for direction in Direction8::ALL {
+            assert_eq!(direction.rotate_left().rotate_right(), direction);
+            assert_eq!(
+                direction.opposite().delta(),
+                Point::new(0, 0) - direction.delta()
+            );
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use crate::direction::{Direction, Direction8};
+use crate::parsing::ParseError;
+use std::ops::{Add, Index, IndexMut, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) struct Point {
+    pub(crate) x: i32,
+    pub(crate) y: i32,
+}
+
+impl Point {
+    pub(crate) const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    pub(crate) fn neighbours_4(self) -> [Point; 4] {
+        Direction::ALL.map(|direction| self + direction.delta())
+    }
+
+    pub(crate) fn neighbours_8(self) -> [Point; 8] {
+        Direction8::ALL.map(|direction| self + direction.delta())
+    }
+
+    pub(crate) fn manhattan_distance(self, other: Point) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub(crate) fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub(crate) fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width: usize = rows.first()?.len();
+
+        if width == 0 || rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub(crate) fn parse(
+        file_content: &str,
+        parse_cell: impl Fn(char) -> Option<T>,
+        expected: &'static str,
+    ) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+
+        for (line_index, line) in file_content.lines().enumerate() {
+            let row: Vec<T> = line
+                .char_indices()
+                .map(|(i, char)| {
+                    parse_cell(char).ok_or_else(|| {
+                        let token: &str = &line[i..i + char.len_utf8()];
+                        ParseError::invalid_value(line_index, line, token, expected)
+                    })
+                })
+                .collect::<Result<_, ParseError>>()?;
+
+            if rows
+                .first()
+                .is_some_and(|first_row| first_row.len() != row.len())
+            {
+                return Err(ParseError::unexpected_format(
+                    line_index,
+                    line,
+                    line,
+                    "rows of equal length",
+                ));
+            }
+
+            rows.push(row);
+        }
+
+        Grid::from_rows(rows).ok_or(ParseError::missing_element("a grid"))
+    }
+
+    pub(crate) fn map<U>(&self, convert: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(convert).collect(),
+        }
+    }
+
+    pub(crate) fn width(&self) -> usize {
+        self.width
+    }
+
+    pub(crate) fn height(&self) -> usize {
+        self.height
+    }
+
+    pub(crate) fn contains(&self, point: Point) -> bool {
+        point.x >= 0
+            && point.y >= 0
+            && (point.x as usize) < self.width
+            && (point.y as usize) < self.height
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        Point::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    pub(crate) fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.point_of(index), cell))
+    }
+
+    pub(crate) fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.cells
+            .iter()
+            .position(|cell| cell == value)
+            .map(|index| self.point_of(index))
+    }
+
+    pub(crate) fn neighbours_4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours_4()
+            .into_iter()
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    pub(crate) fn neighbours_8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours_8()
+            .into_iter()
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    fn ray(&self, start: Point, delta: Point) -> impl Iterator<Item = &T> {
+        std::iter::successors(Some(start), move |&point| Some(point + delta))
+            .map_while(|point| self.get(point))
+    }
+
+    pub(crate) fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub(crate) fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width as i32).map(|x| self.ray(Point::new(x, 0), Point::new(0, 1)))
+    }
+
+    pub(crate) fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height): (i32, i32) = (self.width as i32, self.height as i32);
+
+        (0..height)
+            .rev()
+            .map(|y| Point::new(0, y))
+            .chain((1..width).map(|x| Point::new(x, 0)))
+            .map(|start| self.ray(start, Point::new(1, 1)))
+    }
+
+    pub(crate) fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height): (i32, i32) = (self.width as i32, self.height as i32);
+
+        (0..width)
+            .map(|x| Point::new(x, 0))
+            .chain((1..height).map(move |y| Point::new(width - 1, y)))
+            .map(|start| self.ray(start, Point::new(-1, 1)))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        let index: usize = self
+            .index_of(point)
+            .expect("Point should be inside the grid");
+        &self.cells[index]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let index: usize = self
+            .index_of(point)
+            .expect("Point should be inside the grid");
+        &mut self.cells[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::*;
+
+    fn parse_letters(file_content: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(
+            file_content,
+            |char| char.is_ascii_uppercase().then_some(char),
+            "an uppercase letter",
+        )
+    }
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn access_cells_and_neighbours() {
+        let grid = parse_letters("ABC\nDEF").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'F'));
+        assert_eq!(grid.get(Point::new(3, 1)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid[Point::new(1, 0)], 'B');
+        assert_eq!(grid.find(&'E'), Some(Point::new(1, 1)));
+        assert_eq!(
+            grid.neighbours_4(Point::new(0, 0)).collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(Point::new(1, 1).neighbours_8()[1], Point::new(2, 2));
+        assert_eq!(
+            grid.neighbours_8(Point::new(2, 0)).collect::<Vec<_>>(),
+            [Point::new(2, 1), Point::new(1, 1), Point::new(1, 0)]
+        );
+        assert_eq!(Point::new(0, 3).manhattan_distance(Point::new(2, 0)), 5);
+    }
+
+    #[test]
+    fn iterate_lines() {
+        let grid = parse_letters("ABC\nDEF").unwrap();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, ["ABC", "DEF"]);
+        assert_eq!(collect(grid.columns()), ["AD", "BE", "CF"]);
+        assert_eq!(collect(grid.diagonals()), ["D", "AE", "BF", "C"]);
+        assert_eq!(collect(grid.anti_diagonals()), ["A", "BD", "CE", "F"]);
+    }
+
+    #[test]
+    fn report_invalid_grid() {
+        assert_eq!(
+            parse_letters("AB\nA?"),
+            Err(ParseError::InvalidValue {
+                day: None,
+                line: 2,
+                column: 2,
+                text: String::from("?"),
+                expected: "an uppercase letter",
+            })
+        );
+        assert_eq!(
+            parse_letters("ABC\nAB").unwrap_err().to_string(),
+            "Line 2, column 1: unexpected 'AB', expected rows of equal length"
+        );
+        assert_eq!(
+            parse_letters(""),
+            Err(ParseError::missing_element("a grid"))
+        );
+        assert_eq!(
+            parse_letters("\n\n"),
+            Err(ParseError::missing_element("a grid"))
+        );
+    }
+}
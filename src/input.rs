@@ -0,0 +1,65 @@
+use std::io::Read;
+use std::path::PathBuf;
+
+pub(crate) const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+const DEFAULT_INPUT_DIR: &str = "./input";
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum InputSource {
+    Directory(PathBuf),
+    File(PathBuf),
+    Text(String),
+}
+
+impl InputSource {
+    pub(crate) fn resolve(
+        input: Option<&str>,
+        input_dir: Option<&str>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        if let Some(input) = input {
+            if input == "-" {
+                let mut text = String::new();
+                std::io::stdin().read_to_string(&mut text)?;
+                return Ok(InputSource::Text(text));
+            }
+
+            return Ok(InputSource::File(PathBuf::from(input)));
+        }
+
+        let directory: String = match input_dir {
+            Some(input_dir) => input_dir.to_owned(),
+            None => std::env::var(INPUT_DIR_VARIABLE).unwrap_or(DEFAULT_INPUT_DIR.to_owned()),
+        };
+
+        Ok(InputSource::Directory(PathBuf::from(directory)))
+    }
+
+    pub(crate) fn read(&self, day: u8) -> Result<String, Box<dyn std::error::Error>> {
+        let file_path: PathBuf = match self {
+            InputSource::Directory(directory) => directory.join(format!("{:02}.txt", day)),
+            InputSource::File(file_path) => file_path.clone(),
+            InputSource::Text(text) => return Ok(text.clone()),
+        };
+
+        std::fs::read_to_string(&file_path)
+            .map_err(|error| format!("Failed to read '{}': {}", file_path.display(), error).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::*;
+
+    #[test]
+    fn read_from_different_sources() {
+        let directory = InputSource::resolve(None, Some("./test_input")).unwrap();
+        assert_eq!(directory.read(7).unwrap().lines().count(), 9);
+        assert!(directory.read(26).is_err());
+
+        let file = InputSource::resolve(Some("./test_input/03_02.txt"), None).unwrap();
+        assert_eq!(file.read(3).unwrap(), file.read(4).unwrap());
+
+        let text = InputSource::Text(String::from("1   2"));
+        assert_eq!(text.read(1).unwrap(), "1   2");
+    }
+}
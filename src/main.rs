@@ -1,3 +1,5 @@
+mod bench;
+mod cli;
 mod day_01_historian_hysteria;
 mod day_02_red_nosed_reports;
 mod day_03_mull_it_over;
@@ -23,70 +25,205 @@ mod day_22_monkey_market;
 mod day_23_lan_party;
 mod day_24_crossed_wires;
 mod day_25_code_chronicle;
+mod direction;
+mod grid;
+mod input;
+mod parsing;
+mod render;
+mod report;
+mod runner;
+mod search;
+mod solution;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let solutions = [
-        day_01_historian_hysteria::part_1::solve,
-        day_01_historian_hysteria::part_2::solve,
-        day_02_red_nosed_reports::part_1::solve,
-        day_02_red_nosed_reports::part_2::solve,
-        day_03_mull_it_over::part_1::solve,
-        day_03_mull_it_over::part_2::solve,
-        day_04_ceres_search::part_1::solve,
-        day_04_ceres_search::part_2::solve,
-        day_05_print_queue::part_1::solve,
-        day_05_print_queue::part_2::solve,
-        day_06_guard_gallivant::part_1::solve,
-        day_06_guard_gallivant::part_2::solve,
-        day_07_bridge_repair::part_1::solve,
-        day_07_bridge_repair::part_2::solve,
-        day_08_resonant_collinearity::part_1::solve,
-        day_08_resonant_collinearity::part_2::solve,
-        day_09_disk_fragmenter::part_1::solve,
-        day_09_disk_fragmenter::part_2::solve,
-        day_10_hoof_it::part_1::solve,
-        day_10_hoof_it::part_2::solve,
-        day_11_plutonian_pebbles::part_1::solve,
-        day_11_plutonian_pebbles::part_2::solve,
-        day_12_garden_groups::part_1::solve,
-        day_12_garden_groups::part_2::solve,
-        day_13_claw_contraption::part_1::solve,
-        day_13_claw_contraption::part_2::solve,
-        day_14_restroom_redoubt::part_1::solve,
-        day_14_restroom_redoubt::part_2::solve,
-        day_15_warehouse_woes::part_1::solve,
-        day_15_warehouse_woes::part_2::solve,
-        day_16_reindeer_maze::part_1::solve,
-        day_16_reindeer_maze::part_2::solve,
-        day_17_chronospatial_computer::part_1::solve,
-        day_17_chronospatial_computer::part_2::solve,
-        day_18_ram_run::part_1::solve,
-        day_18_ram_run::part_2::solve,
-        day_19_linen_layout::part_1::solve,
-        day_19_linen_layout::part_2::solve,
-        day_20_race_condition::part_1::solve,
-        day_20_race_condition::part_2::solve,
-        day_21_keypad_conundrum::part_1::solve,
-        day_21_keypad_conundrum::part_2::solve,
-        day_22_monkey_market::part_1::solve,
-        day_22_monkey_market::part_2::solve,
-        day_23_lan_party::part_1::solve,
-        day_23_lan_party::part_2::solve,
-        day_24_crossed_wires::part_1::solve,
-        day_24_crossed_wires::part_2::solve,
-        day_25_code_chronicle::part_1::solve,
-    ];
-
-    for solution in solutions {
-        let start = std::time::Instant::now();
-        let result = solution()?;
-        let duration = start.elapsed();
-        println!(
-            "{} Solved in {:.3}ms.",
-            result,
-            duration.as_secs_f64() * 1000_f64
-        );
+use cli::{BenchOptions, Command, PuzzleOptions, RunOptions, Selection};
+use input::InputSource;
+use render::RenderFormat;
+use solution::Registry;
+use std::path::Path;
+use std::process::ExitCode;
+
+const FRAMES_DIR: &str = "./frames";
+
+fn build_registry(puzzle_options: &PuzzleOptions) -> Registry {
+    let mut registry = Registry::default();
+    day_01_historian_hysteria::register(&mut registry);
+    day_02_red_nosed_reports::register(&mut registry);
+    day_03_mull_it_over::register(&mut registry);
+    day_04_ceres_search::register(&mut registry);
+    day_05_print_queue::register(&mut registry);
+    day_06_guard_gallivant::register(&mut registry);
+    day_07_bridge_repair::register(&mut registry);
+    day_08_resonant_collinearity::register(&mut registry);
+    day_09_disk_fragmenter::register(&mut registry);
+    day_10_hoof_it::register(&mut registry);
+    day_11_plutonian_pebbles::register(&mut registry);
+    day_12_garden_groups::register(&mut registry);
+    day_13_claw_contraption::register(&mut registry);
+    day_14_restroom_redoubt::register(&mut registry, puzzle_options);
+    day_15_warehouse_woes::register(&mut registry);
+    day_16_reindeer_maze::register(&mut registry);
+    day_17_chronospatial_computer::register(&mut registry);
+    day_18_ram_run::register(&mut registry, puzzle_options);
+    day_19_linen_layout::register(&mut registry);
+    day_20_race_condition::register(&mut registry);
+    day_21_keypad_conundrum::register(&mut registry);
+    day_22_monkey_market::register(&mut registry);
+    day_23_lan_party::register(&mut registry);
+    day_24_crossed_wires::register(&mut registry);
+    day_25_code_chronicle::register(&mut registry);
+    registry
+}
+
+fn read_tool_input(options: &RunOptions, day: u8) -> Option<String> {
+    match InputSource::resolve(options.input.as_deref(), options.input_dir.as_deref())
+        .and_then(|input_source| input_source.read(day))
+    {
+        Ok(file_content) => Some(file_content),
+        Err(error) => {
+            eprintln!("Failed to read input: {}", error);
+            None
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let command: Command = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let (options, bench_options): (RunOptions, Option<BenchOptions>) = match command {
+        Command::Run(options) => (options, None),
+        Command::Bench(options, bench_options) => (options, Some(bench_options)),
+        Command::Vm(options, vm_options) => {
+            let Some(file_content) = read_tool_input(&options, 17) else {
+                return ExitCode::FAILURE;
+            };
+
+            return match day_17_chronospatial_computer::run_vm_command(&vm_options, &file_content) {
+                true => ExitCode::SUCCESS,
+                false => ExitCode::FAILURE,
+            };
+        }
+        Command::Circuit(options, action) => {
+            let Some(file_content) = read_tool_input(&options, 24) else {
+                return ExitCode::FAILURE;
+            };
+
+            return match day_24_crossed_wires::run_circuit_command(action, &file_content) {
+                true => ExitCode::SUCCESS,
+                false => ExitCode::FAILURE,
+            };
+        }
+        Command::Tree(options, tree_options) => {
+            let Some(file_content) = read_tool_input(&options, 14) else {
+                return ExitCode::FAILURE;
+            };
+
+            return match day_14_restroom_redoubt::run_tree_command(
+                &tree_options,
+                &options.puzzle,
+                &file_content,
+            ) {
+                true => ExitCode::SUCCESS,
+                false => ExitCode::FAILURE,
+            };
+        }
+        Command::Warehouse(options, warehouse_options) => {
+            let Some(file_content) = read_tool_input(&options, 15) else {
+                return ExitCode::FAILURE;
+            };
+
+            return match day_15_warehouse_woes::run_warehouse_command(
+                &warehouse_options,
+                &file_content,
+            ) {
+                true => ExitCode::SUCCESS,
+                false => ExitCode::FAILURE,
+            };
+        }
+        Command::Routes(options, routes_options) => {
+            let Some(file_content) = read_tool_input(&options, 16) else {
+                return ExitCode::FAILURE;
+            };
+
+            return match day_16_reindeer_maze::run_routes_command(&routes_options, &file_content) {
+                true => ExitCode::SUCCESS,
+                false => ExitCode::FAILURE,
+            };
+        }
+        Command::List => {
+            for solution in build_registry(&PuzzleOptions::default()).iter() {
+                println!(
+                    "Day {:>2} Part {}: {}",
+                    solution.day(),
+                    solution.part(),
+                    solution.title()
+                );
+            }
+
+            return ExitCode::SUCCESS;
+        }
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            return ExitCode::SUCCESS;
+        }
+    };
+
+    let registry: Registry = build_registry(&options.puzzle);
+    let selection: &Selection = &options.selection;
+
+    for &day in &selection.days {
+        if !registry.contains_day(day) {
+            eprintln!("Day {} does not exist. Available days: 1-25.", day);
+            return ExitCode::from(2);
+        }
+
+        if let Some(part) = selection
+            .part
+            .filter(|&part| registry.get(day, part).is_none())
+        {
+            eprintln!("Day {} has no part {}.", day, part);
+            return ExitCode::from(2);
+        }
     }
 
-    Ok(())
+    if options.input.is_some() && selection.days.len() != 1 {
+        eprintln!("--input requires exactly one selected day.");
+        return ExitCode::from(2);
+    }
+
+    let input_source: InputSource =
+        match InputSource::resolve(options.input.as_deref(), options.input_dir.as_deref()) {
+            Ok(input_source) => input_source,
+            Err(error) => {
+                eprintln!("Failed to read input: {}", error);
+                return ExitCode::FAILURE;
+            }
+        };
+
+    let succeeded: bool = match bench_options {
+        Some(bench_options) => bench::run(&registry, selection, &input_source, &bench_options),
+        None => {
+            let frames_dir: &Path = Path::new(options.frames_dir.as_deref().unwrap_or(FRAMES_DIR));
+            let visualize: Option<(RenderFormat, &Path)> = options
+                .visualize
+                .map(|render_format| (render_format, frames_dir));
+            runner::run(
+                &registry,
+                selection,
+                &input_source,
+                options.format,
+                visualize,
+            )
+        }
+    };
+
+    match succeeded {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
 }
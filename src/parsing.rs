@@ -0,0 +1,178 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum ParseError {
+    InvalidValue {
+        day: Option<u8>,
+        line: usize,
+        column: usize,
+        text: String,
+        expected: &'static str,
+    },
+    UnexpectedFormat {
+        day: Option<u8>,
+        line: usize,
+        column: usize,
+        text: String,
+        expected: &'static str,
+    },
+    MissingElement {
+        day: Option<u8>,
+        expected: &'static str,
+    },
+}
+
+fn column_of(line: &str, token: &str) -> usize {
+    let offset: Option<usize> = (token.as_ptr() as usize).checked_sub(line.as_ptr() as usize);
+
+    match offset {
+        Some(offset) if offset <= line.len() && line.is_char_boundary(offset) => {
+            line[..offset].chars().count() + 1
+        }
+        _ => 1,
+    }
+}
+
+impl ParseError {
+    pub(crate) fn invalid_value(
+        line_index: usize,
+        line: &str,
+        token: &str,
+        expected: &'static str,
+    ) -> Self {
+        ParseError::InvalidValue {
+            day: None,
+            line: line_index + 1,
+            column: column_of(line, token),
+            text: token.to_owned(),
+            expected,
+        }
+    }
+
+    pub(crate) fn unexpected_format(
+        line_index: usize,
+        line: &str,
+        token: &str,
+        expected: &'static str,
+    ) -> Self {
+        ParseError::UnexpectedFormat {
+            day: None,
+            line: line_index + 1,
+            column: column_of(line, token),
+            text: token.to_owned(),
+            expected,
+        }
+    }
+
+    pub(crate) fn missing_element(expected: &'static str) -> Self {
+        ParseError::MissingElement {
+            day: None,
+            expected,
+        }
+    }
+
+    pub(crate) fn with_day(mut self, new_day: u8) -> Self {
+        match &mut self {
+            ParseError::InvalidValue { day, .. }
+            | ParseError::UnexpectedFormat { day, .. }
+            | ParseError::MissingElement { day, .. } => *day = Some(new_day),
+        }
+
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (day, position) = match self {
+            ParseError::InvalidValue {
+                day, line, column, ..
+            }
+            | ParseError::UnexpectedFormat {
+                day, line, column, ..
+            } => (day, Some((line, column))),
+            ParseError::MissingElement { day, .. } => (day, None),
+        };
+
+        match (day, position) {
+            (Some(day), Some((line, column))) => {
+                write!(f, "Day {}, line {}, column {}: ", day, line, column)?
+            }
+            (None, Some((line, column))) => write!(f, "Line {}, column {}: ", line, column)?,
+            (Some(day), None) => write!(f, "Day {}: ", day)?,
+            (None, None) => {}
+        }
+
+        match self {
+            ParseError::InvalidValue { text, expected, .. } => {
+                write!(f, "invalid value '{}', expected {}", text, expected)
+            }
+            ParseError::UnexpectedFormat { text, expected, .. } => {
+                write!(f, "unexpected '{}', expected {}", text, expected)
+            }
+            ParseError::MissingElement { expected, .. } => {
+                write!(f, "input is missing {}", expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub(crate) fn parse_value<T: FromStr>(
+    line_index: usize,
+    line: &str,
+    token: &str,
+    expected: &'static str,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::invalid_value(line_index, line, token, expected))
+}
+
+pub(crate) fn split_at_delimiter<'a>(
+    line_index: usize,
+    line: &str,
+    text: &'a str,
+    delimiter: &str,
+    expected: &'static str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::unexpected_format(line_index, line, text, expected))
+}
+
+pub(crate) fn parse_digits(line_index: usize, line: &str) -> Result<Vec<u32>, ParseError> {
+    line.char_indices()
+        .map(|(i, char)| {
+            char.to_digit(10).ok_or_else(|| {
+                let token: &str = &line[i..i + char.len_utf8()];
+                ParseError::invalid_value(line_index, line, token, "a digit between 0 and 9")
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parsing::*;
+
+    #[test]
+    fn report_position_of_invalid_value() {
+        let line = "Register A: 7x9";
+        let (_, number) = split_at_delimiter(2, line, line, ": ", "': '").unwrap();
+        let error = parse_value::<u32>(2, line, number, "a number").unwrap_err();
+        assert_eq!(
+            error.with_day(17).to_string(),
+            "Day 17, line 3, column 13: invalid value '7x9', expected a number"
+        );
+    }
+
+    #[test]
+    fn report_invalid_digit() {
+        assert_eq!(
+            parse_digits(0, "12a").unwrap_err().to_string(),
+            "Line 1, column 3: invalid value 'a', expected a digit between 0 and 9"
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use crate::grid::Grid;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const PIXELS_PER_CELL: usize = 4;
+const PNG_SIGNATURE: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+const MAX_STORED_BLOCK: usize = 65535;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum RenderFormat {
+    Ansi,
+    Text,
+    Ppm,
+    Png,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Style {
+    Plain,
+    Wall,
+    Box,
+    Visited,
+    Path,
+    Actor,
+}
+
+impl Style {
+    fn color(self) -> [u8; 3] {
+        match self {
+            Style::Plain => [24, 24, 32],
+            Style::Wall => [120, 120, 135],
+            Style::Box => [214, 152, 62],
+            Style::Visited => [60, 110, 170],
+            Style::Path => [90, 200, 110],
+            Style::Actor => [235, 70, 70],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) struct Tile {
+    pub(crate) symbol: char,
+    pub(crate) style: Style,
+}
+
+impl Tile {
+    pub(crate) const fn new(symbol: char, style: Style) -> Self {
+        Tile { symbol, style }
+    }
+
+    pub(crate) fn from_symbol(symbol: char) -> Self {
+        let style: Style = match symbol {
+            '#' => Style::Wall,
+            'O' | '[' | ']' => Style::Box,
+            '@' | '^' | 'S' | 'E' => Style::Actor,
+            _ => Style::Plain,
+        };
+
+        Tile::new(symbol, style)
+    }
+}
+
+pub(crate) struct Renderer<'a> {
+    format: RenderFormat,
+    output: &'a mut dyn Write,
+    frames_dir: PathBuf,
+    name: String,
+    frame_count: usize,
+}
+
+impl<'a> Renderer<'a> {
+    pub(crate) fn new(
+        format: RenderFormat,
+        output: &'a mut dyn Write,
+        frames_dir: &Path,
+        name: String,
+    ) -> Self {
+        Renderer {
+            format,
+            output,
+            frames_dir: frames_dir.to_path_buf(),
+            name,
+            frame_count: 0,
+        }
+    }
+
+    pub(crate) fn frame_count(&self) -> usize {
+        self.frame_count
+    }
+
+    pub(crate) fn render(&mut self, frame: &Grid<Tile>) -> io::Result<()> {
+        let (extension, bytes): (&str, Vec<u8>) = match self.format {
+            RenderFormat::Text => (
+                "",
+                format_frame(frame, |tile| tile.symbol.to_string()).into_bytes(),
+            ),
+            RenderFormat::Ansi => ("", format_ansi_frame(frame).into_bytes()),
+            RenderFormat::Ppm => ("ppm", encode_ppm(frame)),
+            RenderFormat::Png => ("png", encode_png(frame)),
+        };
+
+        match extension {
+            "" => writeln!(self.output, "{}", String::from_utf8_lossy(&bytes))?,
+            _ => {
+                fs::create_dir_all(&self.frames_dir)?;
+                let file_path: PathBuf = self.frames_dir.join(format!(
+                    "{}_{:04}.{}",
+                    self.name, self.frame_count, extension
+                ));
+                fs::write(&file_path, bytes)?;
+                writeln!(self.output, "Wrote {}", file_path.display())?;
+            }
+        }
+
+        self.frame_count += 1;
+        Ok(())
+    }
+}
+
+fn format_frame(frame: &Grid<Tile>, format_tile: impl Fn(&Tile) -> String) -> String {
+    frame
+        .rows()
+        .map(|row| row.iter().map(&format_tile).collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+        + "\n"
+}
+
+pub(crate) fn format_ansi_frame(frame: &Grid<Tile>) -> String {
+    format_frame(frame, format_ansi_tile)
+}
+
+fn format_ansi_tile(tile: &Tile) -> String {
+    match tile.style {
+        Style::Plain => tile.symbol.to_string(),
+        style => {
+            let [r, g, b]: [u8; 3] = style.color();
+            format!("\x1b[38;2;{};{};{}m{}\x1b[0m", r, g, b, tile.symbol)
+        }
+    }
+}
+
+fn get_pixel_rows(frame: &Grid<Tile>) -> Vec<Vec<u8>> {
+    let mut pixel_rows: Vec<Vec<u8>> = Vec::new();
+
+    for row in frame.rows() {
+        let pixels: Vec<u8> = row
+            .iter()
+            .flat_map(|tile| [tile.style.color(); PIXELS_PER_CELL])
+            .flatten()
+            .collect();
+
+        for _ in 0..PIXELS_PER_CELL {
+            pixel_rows.push(pixels.clone());
+        }
+    }
+
+    pixel_rows
+}
+
+fn encode_ppm(frame: &Grid<Tile>) -> Vec<u8> {
+    let mut bytes: Vec<u8> = format!(
+        "P6\n{} {}\n255\n",
+        frame.width() * PIXELS_PER_CELL,
+        frame.height() * PIXELS_PER_CELL
+    )
+    .into_bytes();
+    bytes.extend(get_pixel_rows(frame).concat());
+    bytes
+}
+
+fn calculate_crc32(bytes: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFF_FFFF;
+
+    for &byte in bytes {
+        crc ^= byte as u32;
+
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xEDB8_8320,
+                _ => crc >> 1,
+            };
+        }
+    }
+
+    !crc
+}
+
+fn calculate_adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b): (u32, u32) = (1, 0);
+
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+
+    (b << 16) | a
+}
+
+fn compress_stored(bytes: &[u8]) -> Vec<u8> {
+    let mut zlib: Vec<u8> = vec![0x78, 0x01];
+    let blocks: Vec<&[u8]> = bytes.chunks(MAX_STORED_BLOCK).collect();
+
+    for (index, block) in blocks.iter().enumerate() {
+        let length: u16 = block.len() as u16;
+        zlib.push((index + 1 == blocks.len()) as u8);
+        zlib.extend(length.to_le_bytes());
+        zlib.extend((!length).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+
+    if blocks.is_empty() {
+        zlib.extend([1, 0, 0, 0xFF, 0xFF]);
+    }
+
+    zlib.extend(calculate_adler32(bytes).to_be_bytes());
+    zlib
+}
+
+fn push_png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start: usize = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc: u32 = calculate_crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+fn encode_png(frame: &Grid<Tile>) -> Vec<u8> {
+    let mut header: Vec<u8> = Vec::new();
+    header.extend(((frame.width() * PIXELS_PER_CELL) as u32).to_be_bytes());
+    header.extend(((frame.height() * PIXELS_PER_CELL) as u32).to_be_bytes());
+    header.extend([8, 2, 0, 0, 0]);
+
+    let scanlines: Vec<u8> = get_pixel_rows(frame)
+        .into_iter()
+        .flat_map(|row| std::iter::once(0).chain(row))
+        .collect();
+
+    let mut png: Vec<u8> = PNG_SIGNATURE.to_vec();
+    push_png_chunk(&mut png, b"IHDR", &header);
+    push_png_chunk(&mut png, b"IDAT", &compress_stored(&scanlines));
+    push_png_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Point;
+    use crate::render::*;
+
+    fn build_frame() -> Grid<Tile> {
+        let maze = Grid::parse("#.\nS#", Some, "any character").unwrap();
+        let mut frame = maze.map(|&symbol| Tile::from_symbol(symbol));
+        frame[Point::new(1, 0)] = Tile::new('o', Style::Path);
+        frame
+    }
+
+    #[test]
+    fn render_text_and_ansi_frames() {
+        let mut output: Vec<u8> = Vec::new();
+        let mut renderer =
+            Renderer::new(RenderFormat::Text, &mut output, Path::new("."), "t".into());
+        renderer.render(&build_frame()).unwrap();
+        assert_eq!(renderer.frame_count(), 1);
+        assert_eq!(String::from_utf8(output).unwrap(), "#o\nS#\n\n");
+
+        let tile = format_ansi_tile(&Tile::new('o', Style::Path));
+        assert_eq!(tile, "\x1b[38;2;90;200;110mo\x1b[0m");
+        assert_eq!(format_ansi_tile(&Tile::new('.', Style::Plain)), ".");
+    }
+
+    #[test]
+    fn encode_image_frames() {
+        let ppm = encode_ppm(&build_frame());
+        assert!(ppm.starts_with(b"P6\n8 8\n255\n"));
+        assert_eq!(ppm.len(), 11 + 8 * 8 * 3);
+
+        let png = encode_png(&build_frame());
+        assert_eq!(png[..8], PNG_SIGNATURE);
+        assert_eq!(png[png.len() - 8..], *b"IEND\xAE\x42\x60\x82");
+        assert_eq!(calculate_crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(calculate_adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+}
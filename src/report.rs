@@ -0,0 +1,198 @@
+use crate::solution::Answer;
+use std::time::Duration;
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct RunRecord {
+    pub(crate) day: u8,
+    pub(crate) part: u8,
+    pub(crate) title: &'static str,
+    pub(crate) answer: Option<Answer>,
+    pub(crate) read_time: Duration,
+    pub(crate) parse_time: Duration,
+    pub(crate) solve_time: Duration,
+    pub(crate) error: Option<String>,
+}
+
+const CSV_HEADER: &str = "day,part,title,answer,read_ms,parse_ms,solve_ms,error";
+
+fn milliseconds(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000_f64
+}
+
+fn escape_json(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+
+    for char in text.chars() {
+        match char {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+fn escape_csv(text: &str) -> String {
+    match text.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", text.replace('"', "\"\"")),
+        false => text.to_owned(),
+    }
+}
+
+pub(crate) fn format_text(record: &RunRecord) -> String {
+    match (&record.answer, &record.error) {
+        (Some(answer), _) => format!(
+            "Day {} {} (Part {}): {}. Solved in {:.3}ms (read {:.3}ms, parse {:.3}ms, solve {:.3}ms).",
+            record.day,
+            record.title,
+            record.part,
+            answer,
+            milliseconds(record.read_time + record.parse_time + record.solve_time),
+            milliseconds(record.read_time),
+            milliseconds(record.parse_time),
+            milliseconds(record.solve_time)
+        ),
+        (None, error) => format!(
+            "Day {} {} (Part {}): {}",
+            record.day,
+            record.title,
+            record.part,
+            error.as_deref().unwrap_or("no answer")
+        ),
+    }
+}
+
+fn format_json_record(record: &RunRecord) -> String {
+    let answer: String = match &record.answer {
+        Some(Answer::Number(number)) => number.to_string(),
+        Some(Answer::Text(text)) => escape_json(text),
+        None => String::from("null"),
+    };
+
+    let error: String = match &record.error {
+        Some(error) => escape_json(error),
+        None => String::from("null"),
+    };
+
+    format!(
+        "{{\"day\":{},\"part\":{},\"title\":{},\"answer\":{},\"read_ms\":{:.3},\"parse_ms\":{:.3},\"solve_ms\":{:.3},\"error\":{}}}",
+        record.day,
+        record.part,
+        escape_json(record.title),
+        answer,
+        milliseconds(record.read_time),
+        milliseconds(record.parse_time),
+        milliseconds(record.solve_time),
+        error
+    )
+}
+
+pub(crate) fn format_json(records: &[RunRecord]) -> String {
+    let records: Vec<String> = records
+        .iter()
+        .map(|record| format!("  {}", format_json_record(record)))
+        .collect();
+
+    match records.is_empty() {
+        true => String::from("[]"),
+        false => format!("[\n{}\n]", records.join(",\n")),
+    }
+}
+
+pub(crate) fn format_csv(records: &[RunRecord]) -> String {
+    let mut lines: Vec<String> = vec![CSV_HEADER.to_owned()];
+
+    for record in records {
+        let answer: String = record
+            .answer
+            .as_ref()
+            .map(|answer| escape_csv(&answer.to_string()))
+            .unwrap_or_default();
+
+        lines.push(format!(
+            "{},{},{},{},{:.3},{:.3},{:.3},{}",
+            record.day,
+            record.part,
+            escape_csv(record.title),
+            answer,
+            milliseconds(record.read_time),
+            milliseconds(record.parse_time),
+            milliseconds(record.solve_time),
+            escape_csv(record.error.as_deref().unwrap_or_default())
+        ));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::*;
+
+    fn records() -> Vec<RunRecord> {
+        vec![
+            RunRecord {
+                day: 17,
+                part: 1,
+                title: "Chronospatial Computer",
+                answer: Some(Answer::Text(String::from("4,6,3"))),
+                read_time: Duration::from_micros(40),
+                parse_time: Duration::from_micros(1500),
+                solve_time: Duration::from_micros(250),
+                error: None,
+            },
+            RunRecord {
+                day: 1,
+                part: 2,
+                title: "Historian Hysteria",
+                answer: None,
+                read_time: Duration::ZERO,
+                parse_time: Duration::ZERO,
+                solve_time: Duration::ZERO,
+                error: Some(String::from("Failed to read \"01.txt\"")),
+            },
+        ]
+    }
+
+    #[test]
+    fn format_records_as_json() {
+        assert_eq!(
+            format_json(&records()),
+            "[\n  \
+            {\"day\":17,\"part\":1,\"title\":\"Chronospatial Computer\",\"answer\":\"4,6,3\",\"read_ms\":0.040,\"parse_ms\":1.500,\"solve_ms\":0.250,\"error\":null},\n  \
+            {\"day\":1,\"part\":2,\"title\":\"Historian Hysteria\",\"answer\":null,\"read_ms\":0.000,\"parse_ms\":0.000,\"solve_ms\":0.000,\"error\":\"Failed to read \\\"01.txt\\\"\"}\n\
+            ]"
+        );
+        assert_eq!(format_json(&[]), "[]");
+    }
+
+    #[test]
+    fn format_record_as_text() {
+        assert_eq!(
+            format_text(&records()[0]),
+            "Day 17 Chronospatial Computer (Part 1): 4,6,3. \
+            Solved in 1.790ms (read 0.040ms, parse 1.500ms, solve 0.250ms)."
+        );
+        assert_eq!(
+            format_text(&records()[1]),
+            "Day 1 Historian Hysteria (Part 2): Failed to read \"01.txt\""
+        );
+    }
+
+    #[test]
+    fn format_records_as_csv() {
+        assert_eq!(
+            format_csv(&records()),
+            "day,part,title,answer,read_ms,parse_ms,solve_ms,error\n\
+            17,1,Chronospatial Computer,\"4,6,3\",0.040,1.500,0.250,\n\
+            1,2,Historian Hysteria,,0.000,0.000,0.000,\"Failed to read \"\"01.txt\"\"\""
+        );
+    }
+}
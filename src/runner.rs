@@ -0,0 +1,147 @@
+use crate::cli::{OutputFormat, Selection};
+use crate::input::InputSource;
+use crate::render::{RenderFormat, Renderer};
+use crate::report::{self, RunRecord};
+use crate::solution::{DynSolution, Registry};
+use std::error::Error;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+pub(crate) fn run_solution(solution: &dyn DynSolution, input_source: &InputSource) -> RunRecord {
+    let mut record = RunRecord {
+        day: solution.day(),
+        part: solution.part(),
+        title: solution.title(),
+        answer: None,
+        read_time: Duration::ZERO,
+        parse_time: Duration::ZERO,
+        solve_time: Duration::ZERO,
+        error: None,
+    };
+
+    let start = Instant::now();
+    let file_content = input_source.read(solution.day());
+    record.read_time = start.elapsed();
+
+    let file_content: String = match file_content {
+        Ok(file_content) => file_content,
+        Err(error) => {
+            record.error = Some(error.to_string());
+            return record;
+        }
+    };
+
+    let start = Instant::now();
+    let parsed = solution.parse_boxed(&file_content);
+    record.parse_time = start.elapsed();
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            record.error = Some(error.to_string());
+            return record;
+        }
+    };
+
+    let start = Instant::now();
+    let answer = solution.solve_boxed(parsed.as_ref());
+    record.solve_time = start.elapsed();
+
+    match answer {
+        Ok(answer) => record.answer = Some(answer),
+        Err(error) => record.error = Some(error.to_string()),
+    }
+
+    record
+}
+
+fn visualize_solution(
+    solution: &dyn DynSolution,
+    input_source: &InputSource,
+    render_format: RenderFormat,
+    frames_dir: &Path,
+    format: OutputFormat,
+) -> Result<usize, Box<dyn Error>> {
+    let file_content: String = input_source.read(solution.day())?;
+    let parsed = solution.parse_boxed(&file_content)?;
+    let name: String = format!("day_{:02}_part_{}", solution.day(), solution.part());
+    let mut output: Box<dyn Write> = match format {
+        OutputFormat::Text => Box::new(io::stdout().lock()),
+        OutputFormat::Json | OutputFormat::Csv => Box::new(io::stderr().lock()),
+    };
+    let mut renderer = Renderer::new(render_format, output.as_mut(), frames_dir, name);
+    solution.visualize_boxed(parsed.as_ref(), &mut renderer)?;
+    Ok(renderer.frame_count())
+}
+
+pub(crate) fn run(
+    registry: &Registry,
+    selection: &Selection,
+    input_source: &InputSource,
+    format: OutputFormat,
+    visualize: Option<(RenderFormat, &Path)>,
+) -> bool {
+    let mut records: Vec<RunRecord> = Vec::new();
+
+    for solution in registry
+        .iter()
+        .filter(|solution| selection.includes(solution.day(), solution.part()))
+    {
+        let record: RunRecord = run_solution(solution, input_source);
+
+        if format == OutputFormat::Text {
+            match record.error {
+                Some(_) => eprintln!("{}", report::format_text(&record)),
+                None => println!("{}", report::format_text(&record)),
+            }
+        }
+
+        if let (Some((render_format, frames_dir)), None) = (visualize, &record.error) {
+            match visualize_solution(solution, input_source, render_format, frames_dir, format) {
+                Ok(0) if !selection.days.is_empty() => eprintln!(
+                    "Day {} Part {}: no visualization available",
+                    record.day, record.part
+                ),
+                Ok(_) => {}
+                Err(error) => eprintln!(
+                    "Day {} Part {}: failed to visualize: {}",
+                    record.day, record.part, error
+                ),
+            }
+        }
+
+        records.push(record);
+    }
+
+    match format {
+        OutputFormat::Text => {}
+        OutputFormat::Json => println!("{}", report::format_json(&records)),
+        OutputFormat::Csv => println!("{}", report::format_csv(&records)),
+    }
+
+    records.iter().all(|record| record.error.is_none())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_01_historian_hysteria::part_1::Part1;
+    use crate::runner::*;
+    use crate::solution::Answer;
+
+    #[test]
+    fn record_answer_or_error() {
+        let input_source = InputSource::Text(String::from("3   4\n1   3"));
+        let record = run_solution(&Part1, &input_source);
+        assert_eq!(record.answer, Some(Answer::Number(3)));
+        assert_eq!(record.error, None);
+
+        let input_source = InputSource::Text(String::from("3   4\n4   x3"));
+        let record = run_solution(&Part1, &input_source);
+        assert_eq!(record.answer, None);
+        assert_eq!(
+            record.error.as_deref(),
+            Some("Day 1, line 2, column 5: invalid value 'x3', expected a location ID")
+        );
+    }
+}
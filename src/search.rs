@@ -0,0 +1,227 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+#[derive(Debug)]
+pub(crate) struct SearchResult<S> {
+    start: S,
+    distances: HashMap<S, u32>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Copy + Eq + Hash> SearchResult<S> {
+    fn new(start: S) -> Self {
+        SearchResult {
+            start,
+            distances: HashMap::from([(start, 0)]),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    fn relax(&mut self, state: S, next: S, distance: u32) -> bool {
+        match self.distances.get(&next) {
+            Some(&known) if known < distance => false,
+            Some(&known) if known == distance => {
+                self.predecessors.entry(next).or_default().push(state);
+                false
+            }
+            _ => {
+                self.distances.insert(next, distance);
+                self.predecessors.insert(next, vec![state]);
+                true
+            }
+        }
+    }
+
+    pub(crate) fn distance(&self, state: &S) -> Option<u32> {
+        self.distances.get(state).copied()
+    }
+
+    pub(crate) fn distances(&self) -> impl Iterator<Item = (&S, &u32)> {
+        self.distances.iter()
+    }
+
+    pub(crate) fn path_to(&self, target: S) -> Option<Vec<S>> {
+        self.distances.get(&target)?;
+        let mut path: Vec<S> = vec![target];
+        let mut state: S = target;
+
+        while state != self.start {
+            state = self.predecessors[&state][0];
+            path.push(state);
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    pub(crate) fn paths_to(&self, target: S) -> impl Iterator<Item = Vec<S>> + '_ {
+        let mut reversed_paths: Vec<Vec<S>> = match self.distances.contains_key(&target) {
+            true => vec![vec![target]],
+            false => Vec::new(),
+        };
+
+        std::iter::from_fn(move || {
+            while let Some(reversed_path) = reversed_paths.pop() {
+                let state: S = *reversed_path.last().expect("Path should not be empty");
+
+                if state == self.start {
+                    return Some(reversed_path.into_iter().rev().collect());
+                }
+
+                for &previous in self.predecessors.get(&state).into_iter().flatten().rev() {
+                    let mut next_path: Vec<S> = reversed_path.clone();
+                    next_path.push(previous);
+                    reversed_paths.push(next_path);
+                }
+            }
+
+            None
+        })
+    }
+
+    pub(crate) fn states_on_shortest_paths(&self, targets: &[S]) -> HashSet<S> {
+        let mut seen: HashSet<S> = targets
+            .iter()
+            .filter(|target| self.distances.contains_key(target))
+            .copied()
+            .collect();
+        let mut states: Vec<S> = seen.iter().copied().collect();
+
+        while let Some(state) = states.pop() {
+            for &previous in self.predecessors.get(&state).into_iter().flatten() {
+                if seen.insert(previous) {
+                    states.push(previous);
+                }
+            }
+        }
+
+        seen
+    }
+}
+
+pub(crate) fn bfs<S, I>(start: S, mut neighbours: impl FnMut(S) -> I) -> SearchResult<S>
+where
+    S: Copy + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut result: SearchResult<S> = SearchResult::new(start);
+    let mut queue: VecDeque<(S, u32)> = VecDeque::from([(start, 0)]);
+
+    while let Some((state, distance)) = queue.pop_front() {
+        for next in neighbours(state) {
+            if result.relax(state, next, distance + 1) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    result
+}
+
+pub(crate) fn dijkstra<S, I>(start: S, mut neighbours: impl FnMut(S) -> I) -> SearchResult<S>
+where
+    S: Copy + Eq + Hash + Ord,
+    I: IntoIterator<Item = (S, u32)>,
+{
+    let mut result: SearchResult<S> = SearchResult::new(start);
+    let mut p_queue: BinaryHeap<(Reverse<u32>, S)> = BinaryHeap::from([(Reverse(0), start)]);
+
+    while let Some((Reverse(distance), state)) = p_queue.pop() {
+        if result
+            .distance(&state)
+            .is_some_and(|known| known < distance)
+        {
+            continue;
+        }
+
+        for (next, cost) in neighbours(state) {
+            if result.relax(state, next, distance + cost) {
+                p_queue.push((Reverse(distance + cost), next));
+            }
+        }
+    }
+
+    result
+}
+
+pub(crate) fn a_star<S, I>(
+    start: S,
+    goal: S,
+    mut neighbours: impl FnMut(S) -> I,
+    heuristic: impl Fn(S) -> u32,
+) -> SearchResult<S>
+where
+    S: Copy + Eq + Hash + Ord,
+    I: IntoIterator<Item = (S, u32)>,
+{
+    let mut result: SearchResult<S> = SearchResult::new(start);
+    let mut p_queue: BinaryHeap<(Reverse<u32>, Reverse<u32>, S)> =
+        BinaryHeap::from([(Reverse(heuristic(start)), Reverse(0), start)]);
+
+    while let Some((_, Reverse(distance), state)) = p_queue.pop() {
+        if state == goal {
+            break;
+        }
+
+        if result
+            .distance(&state)
+            .is_some_and(|known| known < distance)
+        {
+            continue;
+        }
+
+        for (next, cost) in neighbours(state) {
+            if result.relax(state, next, distance + cost) {
+                let estimate: u32 = distance + cost + heuristic(next);
+                p_queue.push((Reverse(estimate), Reverse(distance + cost), next));
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::*;
+
+    fn grid_neighbours((x, y): (u8, u8)) -> Vec<(u8, u8)> {
+        [(x + 1, y), (x, y + 1)]
+            .into_iter()
+            .filter(|&(nx, ny)| nx <= 2 && ny <= 1)
+            .collect()
+    }
+
+    #[test]
+    fn find_shortest_paths_with_bfs() {
+        let result = bfs((0, 0), grid_neighbours);
+        assert_eq!(result.distance(&(2, 1)), Some(3));
+        assert_eq!(result.distance(&(3, 0)), None);
+        assert_eq!(result.path_to((2, 1)).unwrap().len(), 4);
+        assert_eq!(
+            result.paths_to((1, 1)).collect::<Vec<_>>(),
+            [[(0, 0), (1, 0), (1, 1)], [(0, 0), (0, 1), (1, 1)]]
+        );
+        assert_eq!(result.states_on_shortest_paths(&[(1, 1)]).len(), 4);
+    }
+
+    #[test]
+    fn find_cheapest_paths_with_dijkstra_and_a_star() {
+        let edges = |state: char| match state {
+            'A' => vec![('B', 1), ('C', 5)],
+            'B' => vec![('C', 1), ('D', 7)],
+            'C' => vec![('D', 2)],
+            _ => vec![],
+        };
+
+        let result = dijkstra('A', edges);
+        assert_eq!(result.distance(&'D'), Some(4));
+        assert_eq!(result.path_to('D'), Some(vec!['A', 'B', 'C', 'D']));
+        assert_eq!(result.distances().count(), 4);
+
+        let result = a_star('A', 'D', edges, |_| 0);
+        assert_eq!(result.distance(&'D'), Some(4));
+        assert_eq!(result.path_to('D'), Some(vec!['A', 'B', 'C', 'D']));
+    }
+}
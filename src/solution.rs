@@ -0,0 +1,189 @@
+use crate::parsing::ParseError;
+use crate::render::Renderer;
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Answer {
+    Number(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(number: u64) -> Self {
+        Answer::Number(number)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(number: u32) -> Self {
+        Answer::Number(number as u64)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(number: usize) -> Self {
+        Answer::Number(number as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+pub(crate) trait Solution {
+    type Parsed: 'static;
+
+    const DAY: u8;
+    const PART: u8;
+    const TITLE: &'static str;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn solve(&self, parsed: &Self::Parsed) -> Result<Answer, Box<dyn Error>>;
+
+    fn visualize(&self, _parsed: &Self::Parsed, _renderer: &mut Renderer) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+pub(crate) trait DynSolution {
+    fn day(&self) -> u8;
+    fn part(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn parse_boxed(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn solve_boxed(&self, parsed: &dyn Any) -> Result<Answer, Box<dyn Error>>;
+    fn visualize_boxed(
+        &self,
+        parsed: &dyn Any,
+        renderer: &mut Renderer,
+    ) -> Result<(), Box<dyn Error>>;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn part(&self) -> u8 {
+        S::PART
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse_boxed(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        match self.parse(input) {
+            Ok(parsed) => Ok(Box::new(parsed)),
+            Err(error) => Err(error.with_day(S::DAY)),
+        }
+    }
+
+    fn solve_boxed(&self, parsed: &dyn Any) -> Result<Answer, Box<dyn Error>> {
+        let parsed: &S::Parsed = parsed
+            .downcast_ref()
+            .ok_or("Parsed input does not belong to this solution")?;
+        self.solve(parsed)
+    }
+
+    fn visualize_boxed(
+        &self,
+        parsed: &dyn Any,
+        renderer: &mut Renderer,
+    ) -> Result<(), Box<dyn Error>> {
+        let parsed: &S::Parsed = parsed
+            .downcast_ref()
+            .ok_or("Parsed input does not belong to this solution")?;
+        Ok(self.visualize(parsed, renderer)?)
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Registry {
+    solutions: Vec<Box<dyn DynSolution>>,
+}
+
+impl Registry {
+    pub(crate) fn register<S: Solution + 'static>(&mut self, solution: S) {
+        self.solutions.push(Box::new(solution));
+        self.solutions
+            .sort_by_key(|solution| (solution.day(), solution.part()));
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &dyn DynSolution> {
+        self.solutions.iter().map(|solution| solution.as_ref())
+    }
+
+    pub(crate) fn get(&self, day: u8, part: u8) -> Option<&dyn DynSolution> {
+        self.iter()
+            .find(|solution| solution.day() == day && solution.part() == part)
+    }
+
+    pub(crate) fn contains_day(&self, day: u8) -> bool {
+        self.iter().any(|solution| solution.day() == day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::*;
+
+    struct WordCount;
+
+    impl Solution for WordCount {
+        type Parsed = Vec<String>;
+
+        const DAY: u8 = 26;
+        const PART: u8 = 1;
+        const TITLE: &'static str = "Word Count";
+
+        fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+            input
+                .split_whitespace()
+                .map(|word| match word.chars().all(char::is_alphabetic) {
+                    true => Ok(String::from(word)),
+                    false => Err(ParseError::invalid_value(0, input, word, "a word")),
+                })
+                .collect()
+        }
+
+        fn solve(&self, words: &Self::Parsed) -> Result<Answer, Box<dyn Error>> {
+            Ok(Answer::from(words.len()))
+        }
+    }
+
+    #[test]
+    fn run_registered_solution() {
+        let mut registry = Registry::default();
+        registry.register(WordCount);
+
+        let solution = registry.get(26, 1).unwrap();
+        let parsed = solution.parse_boxed("one two three").unwrap();
+        assert_eq!(solution.title(), "Word Count");
+        assert_eq!(
+            solution.solve_boxed(parsed.as_ref()).unwrap(),
+            Answer::Number(3)
+        );
+        assert!(solution.solve_boxed(&0_u8).is_err());
+        assert_eq!(
+            solution.parse_boxed("one 2").unwrap_err().to_string(),
+            "Day 26, line 1, column 5: invalid value '2', expected a word"
+        );
+        assert!(registry.get(26, 2).is_none());
+        assert!(!registry.contains_day(1));
+    }
+}